@@ -0,0 +1,181 @@
+//! A blocking version of the API, for programs that don't use async.
+//!
+//! Every lookup in this module mirrors one of the async functions of the
+//! crate, but waits for the result instead of returning a future. The
+//! requests are made on a small runtime owned by this module, so callers
+//! don't need to set up one of their own.
+//!
+//! The functions in this module must not be called from inside an async
+//! runtime, as blocking there would stall it. Use the async API instead.
+//!
+//! Requests are made through the program wide default
+//! [`ScryfallClient`], unless a different one is given with [`with_client`].
+//!
+//! This module is only available with the `blocking` feature.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use scryfall::blocking;
+//!
+//! let bolt = blocking::card::named("Lightning Bolt").unwrap();
+//! assert_eq!(bolt.name, "Lightning Bolt");
+//!
+//! for set in blocking::set::all().unwrap().take(5) {
+//!     println!("{}", set.unwrap().name);
+//! }
+//! ```
+pub mod bulk;
+pub mod card;
+pub mod catalog;
+pub mod migration;
+pub mod ruling;
+pub mod set;
+pub mod symbology;
+
+use std::cell::RefCell;
+use std::future::Future;
+
+use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
+use tokio::runtime::Runtime;
+
+pub use self::bulk::BulkIter;
+use crate::ScryfallClient;
+
+static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("scryfall-blocking")
+        .enable_all()
+        .build()
+        .expect("failed to start the runtime for blocking requests")
+});
+
+thread_local! {
+    static CURRENT: RefCell<Option<ScryfallClient>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, making the requests of the blocking functions it calls through
+/// `client`. This is the blocking counterpart of [`ScryfallClient::scope`].
+///
+/// # Examples
+/// ```rust,no_run
+/// use scryfall::{blocking, ScryfallClient};
+///
+/// let client = ScryfallClient::builder()
+///     .user_agent("my-script/0.1")
+///     .build()
+///     .unwrap();
+/// let mmq = blocking::with_client(&client, || blocking::set::code("mmq")).unwrap();
+/// assert_eq!(mmq.name, "Mercadian Masques");
+/// ```
+pub fn with_client<R>(client: &ScryfallClient, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous client, even if `f` panics.
+    struct Reset(Option<ScryfallClient>);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _reset = Reset(CURRENT.with(|current| current.replace(Some(client.clone()))));
+    f()
+}
+
+/// Waits for `future` to complete, with the client given to [`with_client`] as
+/// the current client.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let client = CURRENT
+        .with(|current| current.borrow().clone())
+        .unwrap_or_else(ScryfallClient::current);
+    RUNTIME.block_on(client.scope(future))
+}
+
+/// A blocking iterator over the items of a [`ListIter`](crate::list::ListIter).
+/// Following pages are requested once the previous page has been exhausted.
+pub struct ListIter<T> {
+    inner: crate::list::ListIter<T>,
+}
+
+impl<T> From<crate::list::ListIter<T>> for ListIter<T> {
+    fn from(inner: crate::list::ListIter<T>) -> Self {
+        ListIter { inner }
+    }
+}
+
+impl<T: DeserializeOwned + Send + Sync + Unpin + 'static> ListIter<T> {
+    /// Gets a `ListIter` for the next page of objects by requesting it from the
+    /// API.
+    pub fn next_page(&self) -> crate::Result<Option<Self>> {
+        Ok(block_on(self.inner.next_page())?.map(ListIter::from))
+    }
+
+    /// Converts this iterator back into the async
+    /// [`ListIter`](crate::list::ListIter).
+    pub fn into_async(self) -> crate::list::ListIter<T> {
+        self.inner
+    }
+}
+
+impl<T: DeserializeOwned + Send + Sync + Unpin + 'static> Iterator for ListIter<T> {
+    type Item = crate::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        block_on(self.inner.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::RateLimiter;
+    use crate::list::List;
+    use crate::uri::Uri;
+    use crate::util::test_server::TestServer;
+
+    fn test_client(server: &TestServer) -> ScryfallClient {
+        ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn lookups_use_the_given_client() {
+        let server = block_on(TestServer::start(vec![(
+            200,
+            r#"{"uri":"https://api.scryfall.com/catalog/powers","total_values":1,"data":["*"]}"#,
+        )]));
+        let catalog = with_client(&test_client(&server), catalog::powers).unwrap();
+        assert_eq!(catalog.data, ["*"]);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn list_iter_requests_following_pages() {
+        let server = block_on(TestServer::start(vec![
+            (
+                200,
+                r#"{"object":"list","has_more":true,"next_page":"https://api.scryfall.com/things?page=2","data":["a","b"]}"#,
+            ),
+            (200, r#"{"object":"list","has_more":false,"data":["c"]}"#),
+        ]));
+        let items = with_client(&test_client(&server), || {
+            let uri = Uri::<List<String>>::try_from("https://api.scryfall.com/things").unwrap();
+            ListIter::from(block_on(uri.fetch_iter()).unwrap())
+                .collect::<crate::Result<Vec<_>>>()
+                .unwrap()
+        });
+        assert_eq!(items, ["a", "b", "c"]);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].starts_with("GET /things?page=2 HTTP/1.1"));
+    }
+}
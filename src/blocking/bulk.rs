@@ -0,0 +1,197 @@
+//! Blocking versions of the [bulk data](crate::bulk) downloads.
+//!
+//! The files are parsed on a separate thread as they are read, so the items
+//! can be iterated over without holding the whole file in memory.
+//!
+//! Like the rest of the [`blocking`](crate::blocking) module, the downloads
+//! run on the module's own runtime. Only [`BulkIter::from_reader`], which
+//! reads a file that was already downloaded, runs without any async runtime.
+use std::io::Read;
+use std::path::Path;
+
+use cfg_if::cfg_if;
+use serde::de::DeserializeOwned;
+#[cfg(not(feature = "bulk_caching"))]
+use tokio_util::io::SyncIoBridge;
+use uuid::Uuid;
+
+use super::block_on;
+#[cfg(not(feature = "bulk_caching"))]
+use super::RUNTIME;
+use crate::bulk::BulkDataFile;
+use crate::client::ScryfallClient;
+use crate::ruling::Ruling;
+use crate::util::streaming_deserializer;
+use crate::Card;
+
+/// A blocking iterator over the objects of a bulk data file.
+pub struct BulkIter<T> {
+    inner: std::sync::mpsc::IntoIter<crate::Result<T>>,
+}
+
+impl<T: DeserializeOwned + Send + 'static> BulkIter<T> {
+    /// Iterates over the objects of a bulk data file that was already
+    /// downloaded, for example with [`download`]. Their unknown parts are
+    /// reported to the current client's
+    /// [`DriftRecorder`](crate::client::DriftRecorder), if it has one.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    ///
+    /// use scryfall::blocking::BulkIter;
+    /// use scryfall::Card;
+    ///
+    /// let file = BufReader::new(File::open("oracle-cards.json").unwrap());
+    /// for card in BulkIter::<Card>::from_reader(file) {
+    ///     println!("{}", card.unwrap().name);
+    /// }
+    /// ```
+    pub fn from_reader(reader: impl Read + Send + 'static) -> Self {
+        BulkIter {
+            inner: streaming_deserializer::create_blocking(
+                reader,
+                ScryfallClient::current().schema_drift().cloned(),
+            ),
+        }
+    }
+}
+
+impl<T> Iterator for BulkIter<T> {
+    type Item = crate::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+/// Blocking version of [`BulkDataFile::of_type`].
+pub fn of_type<T: DeserializeOwned>(bulk_type: &str) -> crate::Result<BulkDataFile<T>> {
+    block_on(BulkDataFile::of_type(bulk_type))
+}
+
+/// Blocking version of [`BulkDataFile::id`].
+pub fn id<T: DeserializeOwned>(id: Uuid) -> crate::Result<BulkDataFile<T>> {
+    block_on(BulkDataFile::id(id))
+}
+
+/// Blocking version of [`BulkDataFile::load`].
+pub fn load<T: DeserializeOwned>(file: &BulkDataFile<T>) -> crate::Result<Vec<T>> {
+    block_on(file.load())
+}
+
+/// Blocking version of [`BulkDataFile::load_stream`], returning an iterator
+/// instead of a stream.
+///
+/// With the `bulk_caching` feature the file is downloaded to the temp folder
+/// first, like [`BulkDataFile::load`] does. Otherwise it's parsed as it's
+/// downloaded, without holding the whole file in memory.
+pub fn load_iter<T: DeserializeOwned + Send + 'static>(
+    file: &BulkDataFile<T>,
+) -> crate::Result<BulkIter<T>> {
+    cfg_if! {
+        if #[cfg(feature = "bulk_caching")] {
+            let reader = block_on(file.get_reader())?;
+            Ok(BulkIter::from_reader(reader))
+        } else {
+            let reader = block_on(file.get_async_reader())?;
+            Ok(BulkIter::from_reader(SyncIoBridge::new_with_handle(
+                Box::pin(reader),
+                RUNTIME.handle().clone(),
+            )))
+        }
+    }
+}
+
+/// Blocking version of [`BulkDataFile::download`].
+pub fn download<T: DeserializeOwned>(
+    file: &BulkDataFile<T>,
+    path: impl AsRef<Path>,
+) -> crate::Result<()> {
+    block_on(file.download(path))
+}
+
+/// Blocking version of [`bulk::oracle_cards`](crate::bulk::oracle_cards).
+pub fn oracle_cards() -> crate::Result<BulkIter<Card>> {
+    load_iter(&of_type("oracle_cards")?)
+}
+
+/// Blocking version of [`bulk::unique_artwork`](crate::bulk::unique_artwork).
+pub fn unique_artwork() -> crate::Result<BulkIter<Card>> {
+    load_iter(&of_type("unique_artwork")?)
+}
+
+/// Blocking version of [`bulk::default_cards`](crate::bulk::default_cards).
+pub fn default_cards() -> crate::Result<BulkIter<Card>> {
+    load_iter(&of_type("default_cards")?)
+}
+
+/// Blocking version of [`bulk::all_cards`](crate::bulk::all_cards).
+pub fn all_cards() -> crate::Result<BulkIter<Card>> {
+    load_iter(&of_type("all_cards")?)
+}
+
+/// Blocking version of [`bulk::rulings`](crate::bulk::rulings).
+pub fn rulings() -> crate::Result<BulkIter<Ruling>> {
+    load_iter(&of_type("rulings")?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iterates_without_a_runtime() {
+        let items =
+            BulkIter::<serde_json::Value>::from_reader(&br#"[{"a":1},{"a":2},{"a":3}]"#[..])
+                .map(|item| item.unwrap()["a"].as_u64().unwrap())
+                .collect::<Vec<_>>();
+        assert_eq!(items, [1, 2, 3]);
+    }
+
+    #[test]
+    fn reports_malformed_files() {
+        let mut items = BulkIter::<serde_json::Value>::from_reader(&br#"[{"a":1},{"a":"#[..]);
+        assert!(items.next().unwrap().is_ok());
+        assert!(items.next().unwrap().is_err());
+        assert!(items.next().is_none());
+    }
+
+    #[cfg(not(feature = "bulk_caching"))]
+    #[test]
+    fn streams_downloads_into_the_iterator() {
+        use crate::blocking::with_client;
+        use crate::client::RateLimiter;
+        use crate::util::test_server::TestServer;
+
+        let file = serde_json::json!({
+            "object": "bulk_data",
+            "id": "27bf3214-1271-490b-bdfe-c0be6c23d02e",
+            "type": "oracle_cards",
+            "updated_at": "2025-01-01T10:04:41.282+00:00",
+            "uri": "https://api.scryfall.com/bulk-data/oracle-cards",
+            "name": "Oracle Cards",
+            "description": "A JSON file containing one Scryfall card object for each Oracle ID.",
+            "size": 2048,
+            "download_uri": "https://data.scryfall.io/oracle-cards/oracle-cards.json",
+            "content_type": "application/json",
+            "content_encoding": "gzip",
+        });
+        let server = block_on(TestServer::start(vec![
+            (200, file.to_string()),
+            (200, r#"[{"a":1},{"a":2}]"#.to_string()),
+        ]));
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .build()
+            .unwrap();
+        let items = with_client(&client, || {
+            load_iter(&of_type::<serde_json::Value>("oracle_cards").unwrap()).unwrap()
+        })
+        .map(|item| item.unwrap()["a"].as_u64().unwrap())
+        .collect::<Vec<_>>();
+        assert_eq!(items, [1, 2]);
+    }
+}
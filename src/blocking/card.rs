@@ -0,0 +1,115 @@
+//! Blocking versions of the [`Card`] lookups.
+use uuid::Uuid;
+
+use super::{block_on, ListIter};
+use crate::card::{CardCollection, CardIdentifier, CollectorNumber, Languages, SubtypeCatalog};
+use crate::search::Search;
+use crate::Card;
+use crate::Catalog;
+
+/// Blocking version of [`Card::random`].
+pub fn random() -> crate::Result<Card> {
+    block_on(Card::random())
+}
+
+/// Blocking version of [`Card::search`].
+///
+/// # Examples
+/// ```rust,no_run
+/// use scryfall::blocking;
+///
+/// let zurgos = blocking::card::search("zurgo")
+///     .unwrap()
+///     .collect::<scryfall::Result<Vec<_>>>()
+///     .unwrap();
+/// assert!(zurgos.iter().any(|c| c.name == "Zurgo Bellstriker"));
+/// ```
+pub fn search(query: impl Search) -> crate::Result<ListIter<Card>> {
+    block_on(Card::search(query)).map(ListIter::from)
+}
+
+/// Blocking version of [`Card::search_all`].
+pub fn search_all(query: impl Search) -> crate::Result<Vec<Card>> {
+    block_on(Card::search_all(query))
+}
+
+/// Blocking version of [`Card::search_random`].
+pub fn search_random(query: impl Search) -> crate::Result<Card> {
+    block_on(Card::search_random(query))
+}
+
+/// Blocking version of [`Card::named`].
+pub fn named(name: &str) -> crate::Result<Card> {
+    block_on(Card::named(name))
+}
+
+/// Blocking version of [`Card::named_fuzzy`].
+pub fn named_fuzzy(query: &str) -> crate::Result<Card> {
+    block_on(Card::named_fuzzy(query))
+}
+
+/// Blocking version of [`Card::autocomplete`].
+pub fn autocomplete(query: &str, include_extras: bool) -> crate::Result<Catalog> {
+    block_on(Card::autocomplete(query, include_extras))
+}
+
+/// Blocking version of [`Card::set_and_number`].
+pub fn set_and_number(set_code: &str, number: impl Into<CollectorNumber>) -> crate::Result<Card> {
+    block_on(Card::set_and_number(set_code, number))
+}
+
+/// Blocking version of [`Card::set_number_and_language`].
+pub fn set_number_and_language(
+    set_code: &str,
+    number: impl Into<CollectorNumber>,
+    language: Languages,
+) -> crate::Result<Card> {
+    block_on(Card::set_number_and_language(set_code, number, language))
+}
+
+/// Blocking version of [`Card::in_language`].
+pub fn in_language(card: &Card, language: Languages) -> crate::Result<Card> {
+    block_on(card.in_language(language))
+}
+
+/// Blocking version of [`Card::in_english`].
+pub fn in_english(card: &Card) -> crate::Result<Card> {
+    block_on(card.in_english())
+}
+
+/// Blocking version of [`Card::multiverse`].
+pub fn multiverse(multiverse_id: usize) -> crate::Result<Card> {
+    block_on(Card::multiverse(multiverse_id))
+}
+
+/// Blocking version of [`Card::mtgo`].
+pub fn mtgo(mtgo_id: usize) -> crate::Result<Card> {
+    block_on(Card::mtgo(mtgo_id))
+}
+
+/// Blocking version of [`Card::arena`].
+pub fn arena(arena_id: usize) -> crate::Result<Card> {
+    block_on(Card::arena(arena_id))
+}
+
+/// Blocking version of [`Card::tcgplayer`].
+pub fn tcgplayer(tcgplayer_id: usize) -> crate::Result<Card> {
+    block_on(Card::tcgplayer(tcgplayer_id))
+}
+
+/// Blocking version of [`Card::scryfall_id`].
+pub fn scryfall_id(scryfall_id: Uuid) -> crate::Result<Card> {
+    block_on(Card::scryfall_id(scryfall_id))
+}
+
+/// Blocking version of [`Card::collection`].
+pub fn collection(
+    identifiers: impl IntoIterator<Item = CardIdentifier>,
+) -> crate::Result<CardCollection> {
+    block_on(Card::collection(identifiers))
+}
+
+/// Blocking version of [`SubtypeCatalog::fetch`].
+pub fn subtype_catalog() -> crate::Result<SubtypeCatalog> {
+    block_on(SubtypeCatalog::fetch())
+}
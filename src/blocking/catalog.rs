@@ -0,0 +1,34 @@
+//! Blocking versions of the [`Catalog`] lookups.
+use super::block_on;
+use crate::Catalog;
+
+macro_rules! blocking_catalogs {
+    ($($name:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Blocking version of [`Catalog::", stringify!($name), "`].")]
+            pub fn $name() -> crate::Result<Catalog> {
+                block_on(Catalog::$name())
+            }
+        )*
+    };
+}
+
+blocking_catalogs!(
+    card_names,
+    artist_names,
+    word_bank,
+    creature_types,
+    planeswalker_types,
+    land_types,
+    artifact_types,
+    enchantment_types,
+    spell_types,
+    battle_types,
+    powers,
+    toughnesses,
+    loyalties,
+    watermarks,
+    keyword_abilities,
+    keyword_actions,
+    ability_words,
+);
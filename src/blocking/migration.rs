@@ -0,0 +1,8 @@
+//! Blocking versions of the [`Migration`] lookups.
+use super::{block_on, ListIter};
+use crate::migration::Migration;
+
+/// Blocking version of [`Migration::all`].
+pub fn all() -> crate::Result<ListIter<Migration>> {
+    block_on(Migration::all()).map(ListIter::from)
+}
@@ -0,0 +1,34 @@
+//! Blocking versions of the [`Ruling`] lookups.
+use uuid::Uuid;
+
+use super::{block_on, ListIter};
+use crate::card::CollectorNumber;
+use crate::ruling::Ruling;
+
+/// Blocking version of [`Ruling::multiverse_id`].
+pub fn multiverse_id(id: usize) -> crate::Result<ListIter<Ruling>> {
+    block_on(Ruling::multiverse_id(id)).map(ListIter::from)
+}
+
+/// Blocking version of [`Ruling::mtgo_id`].
+pub fn mtgo_id(id: usize) -> crate::Result<ListIter<Ruling>> {
+    block_on(Ruling::mtgo_id(id)).map(ListIter::from)
+}
+
+/// Blocking version of [`Ruling::arena_id`].
+pub fn arena_id(id: usize) -> crate::Result<ListIter<Ruling>> {
+    block_on(Ruling::arena_id(id)).map(ListIter::from)
+}
+
+/// Blocking version of [`Ruling::set_and_number`].
+pub fn set_and_number(
+    set: &str,
+    number: impl Into<CollectorNumber>,
+) -> crate::Result<ListIter<Ruling>> {
+    block_on(Ruling::set_and_number(set, number)).map(ListIter::from)
+}
+
+/// Blocking version of [`Ruling::uuid`].
+pub fn uuid(id: Uuid) -> crate::Result<ListIter<Ruling>> {
+    block_on(Ruling::uuid(id)).map(ListIter::from)
+}
@@ -0,0 +1,30 @@
+//! Blocking versions of the [`Set`] lookups.
+use uuid::Uuid;
+
+use super::{block_on, ListIter};
+use crate::{Card, Set};
+
+/// Blocking version of [`Set::all`].
+pub fn all() -> crate::Result<ListIter<Set>> {
+    block_on(Set::all()).map(ListIter::from)
+}
+
+/// Blocking version of [`Set::code`].
+pub fn code(code: &str) -> crate::Result<Set> {
+    block_on(Set::code(code))
+}
+
+/// Blocking version of [`Set::tcgplayer`].
+pub fn tcgplayer<T: std::fmt::Display>(code: T) -> crate::Result<Set> {
+    block_on(Set::tcgplayer(code))
+}
+
+/// Blocking version of [`Set::uuid`].
+pub fn uuid(uuid: Uuid) -> crate::Result<Set> {
+    block_on(Set::uuid(uuid))
+}
+
+/// Blocking version of [`Set::cards`].
+pub fn cards(set: &Set) -> crate::Result<ListIter<Card>> {
+    block_on(set.cards()).map(ListIter::from)
+}
@@ -0,0 +1,13 @@
+//! Blocking versions of the [`CardSymbol`] and [`ManaCost`] lookups.
+use super::{block_on, ListIter};
+use crate::symbology::{CardSymbol, ManaCost, ParsedManaCost};
+
+/// Blocking version of [`CardSymbol::all`].
+pub fn all() -> crate::Result<ListIter<CardSymbol>> {
+    block_on(CardSymbol::all()).map(ListIter::from)
+}
+
+/// Blocking version of [`ManaCost::parse_remote`].
+pub fn parse_remote(text: &str) -> crate::Result<ParsedManaCost> {
+    block_on(ManaCost::parse_remote(text))
+}
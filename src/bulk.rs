@@ -17,6 +17,7 @@
 
 use std::io::BufReader;
 use std::path::Path;
+use std::time::Instant;
 
 use cfg_if::cfg_if;
 use chrono::{DateTime, Utc};
@@ -24,7 +25,6 @@ use futures::Stream;
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use tokio::io::AsyncRead;
-use tokio_stream::StreamExt;
 use tokio_util::io::StreamReader;
 use uuid::Uuid;
 
@@ -34,10 +34,11 @@ cfg_if! {
     }
 }
 
-use crate::card::Card;
+use crate::card::{Card, CardRef};
+use crate::client::ScryfallClient;
 use crate::ruling::Ruling;
 use crate::uri::Uri;
-use crate::util::{streaming_deserializer, BULK_DATA_URL};
+use crate::util::{streaming_deserializer, trace, BULK_DATA_URL};
 
 /// Scryfall provides daily exports of our card data in bulk files. Each of
 /// these files is represented as a bulk_data object via the API. URLs for files
@@ -62,7 +63,6 @@ use crate::util::{streaming_deserializer, BULK_DATA_URL};
 /// Bulk data is only collected once every 12 hours. You can use the card API
 /// methods to retrieve fresh objects instead.
 #[derive(Deserialize, Debug, Clone)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct BulkDataFile<T> {
     /// A unique ID for this bulk item.
@@ -119,16 +119,18 @@ impl<T: DeserializeOwned> BulkDataFile<T> {
                 ))
             }
 
-            async fn get_reader(&self) -> crate::Result<BufReader<std::fs::File>> {
+            pub(crate) async fn get_reader(&self) -> crate::Result<BufReader<std::fs::File>> {
                 let cache_path = self.cache_path();
+                trace::bulk_cache(&cache_path, cache_path.exists());
                 if !cache_path.exists() {
                     self.download(&cache_path).await?;
                 }
                 Ok(BufReader::new(std::fs::File::open(cache_path)?))
             }
 
-            async fn get_async_reader(&self) -> crate::Result<impl AsyncRead> {
+            pub(crate) async fn get_async_reader(&self) -> crate::Result<impl AsyncRead + Send> {
                 let cache_path = self.cache_path();
+                trace::bulk_cache(&cache_path, cache_path.exists());
                 if !cache_path.exists() {
                     self.download(&cache_path).await?;
                 }
@@ -138,25 +140,15 @@ impl<T: DeserializeOwned> BulkDataFile<T> {
                 Ok(tokio::io::BufReader::new(file))
             }
         } else {
-            async fn get_reader(&self) -> crate::Result<BufReader<impl std::io::Read + Send>> {
-
-                let response = self.download_uri.fetch_raw().await?;
-                let body = response.bytes().await.map_err(|e| {
-                    crate::Error::ReqwestError { error: Box::new(e), url: self.download_uri.inner().clone() }
-                })?;
+            pub(crate) async fn get_reader(&self) -> crate::Result<BufReader<bytes::buf::Reader<bytes::Bytes>>> {
+                let response = self.download_uri.fetch_raw_with(&ScryfallClient::current()).await?;
+                let body = response.bytes().await?;
                 Ok(BufReader::new(body.reader()))
             }
 
-            async fn get_async_reader(&self) -> crate::Result<impl AsyncRead> {
-                let response = self.download_uri.fetch_raw().await?;
-                let stream = response.bytes_stream()
-                    .map(|bytes_result| {
-                        bytes_result
-                            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))
-                            // .map(|bytes| bytes.to_vec())
-                    });
-
-                Ok(StreamReader::new(stream))
+            pub(crate) async fn get_async_reader(&self) -> crate::Result<impl AsyncRead + Send> {
+                let response = self.download_uri.fetch_raw_with(&ScryfallClient::current()).await?;
+                Ok(StreamReader::new(response.bytes_stream()))
             }
         }
     }
@@ -178,7 +170,8 @@ impl<T: DeserializeOwned> BulkDataFile<T> {
     /// Downloads and stores the file in the computer's temp folder if this
     /// version hasn't been downloaded yet. Otherwise uses the stored copy.
     pub async fn load(&self) -> crate::Result<Vec<T>> {
-        Ok(serde_json::from_reader(self.get_reader().await?)?)
+        let reader = self.get_reader().await?;
+        streaming_deserializer::collect(reader, ScryfallClient::current().schema_drift())
     }
 
     /// Returns an async Stream over the objects from this bulk data download.
@@ -190,21 +183,27 @@ impl<T: DeserializeOwned> BulkDataFile<T> {
         T: Send + 'static,
     {
         let reader = self.get_async_reader().await?;
-        Ok(streaming_deserializer::create(reader))
+        Ok(streaming_deserializer::create(
+            reader,
+            ScryfallClient::current().schema_drift().cloned(),
+        ))
     }
 
     /// Downloads this file, saving it to `path`. Overwrites the file if it
     /// already exists.
     pub async fn download(&self, path: impl AsRef<Path>) -> crate::Result<()> {
         let path = path.as_ref();
-        let response = self.download_uri.fetch_raw().await?;
+        let start = Instant::now();
+        let response = self
+            .download_uri
+            .fetch_raw_with(&ScryfallClient::current())
+            .await?;
 
-        let body = response
-            .bytes_stream()
-            .map(|bytes_result| bytes_result.map_err(std::io::Error::other));
+        let body = response.bytes_stream();
         let mut file = tokio::fs::File::create(path).await?;
 
-        tokio::io::copy(&mut StreamReader::new(body), &mut file).await?;
+        let bytes = tokio::io::copy(&mut StreamReader::new(body), &mut file).await?;
+        trace::bulk_downloaded(self.download_uri.inner(), bytes, start.elapsed());
 
         Ok(())
     }
@@ -252,55 +251,99 @@ pub async fn rulings() -> crate::Result<impl Stream<Item = crate::Result<Ruling>
     BulkDataFile::of_type("rulings").await?.load_stream().await
 }
 
+/// Calls `f` with each card of a bulk data file that has already been read
+/// into memory, or memory mapped, without copying their text.
+///
+/// The file can be saved with [`BulkDataFile::download`]. See [`CardRef`] for
+/// what is borrowed.
+///
+/// # Examples
+/// ```rust,no_run
+/// use scryfall::bulk::{self, BulkDataFile};
+/// use scryfall::Card;
+/// # tokio_test::block_on(async {
+/// let file = BulkDataFile::<Card>::of_type("oracle_cards").await.unwrap();
+/// file.download("oracle-cards.json").await.unwrap();
+///
+/// let json = std::fs::read("oracle-cards.json").unwrap();
+/// let mut reserved = Vec::new();
+/// bulk::for_each_card_ref(&json, |card| {
+///     if card.reserved {
+///         reserved.push(card.name);
+///     }
+/// })
+/// .unwrap();
+/// # })
+/// ```
+pub fn for_each_card_ref<'a>(json: &'a [u8], f: impl FnMut(CardRef<'a>)) -> crate::Result<()> {
+    streaming_deserializer::for_each_borrowed(json, f)
+}
+
 #[cfg(test)]
 mod tests {
     use futures::StreamExt;
 
     use crate::util::streaming_deserializer;
+    use crate::util::test_cassette::with_cassette;
 
     #[tokio::test]
     #[ignore]
     async fn oracle_cards() {
-        let mut stream = super::oracle_cards().await.unwrap();
-        while let Some(card) = stream.next().await {
-            card.unwrap();
-        }
+        with_cassette("oracle_cards", async {
+            let mut stream = super::oracle_cards().await.unwrap();
+            while let Some(card) = stream.next().await {
+                card.unwrap();
+            }
+        })
+        .await
     }
 
     #[tokio::test]
     #[ignore]
     async fn unique_artwork() {
-        let mut stream = super::unique_artwork().await.unwrap();
-        while let Some(card) = stream.next().await {
-            card.unwrap();
-        }
+        with_cassette("unique_artwork", async {
+            let mut stream = super::unique_artwork().await.unwrap();
+            while let Some(card) = stream.next().await {
+                card.unwrap();
+            }
+        })
+        .await
     }
 
     #[tokio::test]
     #[ignore]
     async fn default_cards() {
-        let mut stream = super::default_cards().await.unwrap();
-        while let Some(card) = stream.next().await {
-            card.unwrap();
-        }
+        with_cassette("default_cards", async {
+            let mut stream = super::default_cards().await.unwrap();
+            while let Some(card) = stream.next().await {
+                card.unwrap();
+            }
+        })
+        .await
     }
 
     #[tokio::test]
     #[ignore]
     async fn all_cards() {
-        let mut stream = super::all_cards().await.unwrap();
-        while let Some(card) = stream.next().await {
-            card.unwrap();
-        }
+        with_cassette("all_cards", async {
+            let mut stream = super::all_cards().await.unwrap();
+            while let Some(card) = stream.next().await {
+                card.unwrap();
+            }
+        })
+        .await
     }
 
     #[tokio::test]
     #[ignore]
     async fn rulings() {
-        let mut stream = super::rulings().await.unwrap();
-        while let Some(card) = stream.next().await {
-            card.unwrap();
-        }
+        with_cassette("rulings", async {
+            let mut stream = super::rulings().await.unwrap();
+            while let Some(card) = stream.next().await {
+                card.unwrap();
+            }
+        })
+        .await
     }
 
     #[tokio::test]
@@ -322,11 +365,84 @@ mod tests {
                         "comment": "The “commander tax” increases based on how many times a commander was cast from the command zone. Casting a commander from your hand doesn’t require that additional cost, and it doesn’t increase what the cost will be the next time you cast that commander from the command zone."
                       }
                    ]"#;
-        let mut stream =
-            streaming_deserializer::create(s.as_bytes()).map(|r: crate::Result<Ruling>| r.unwrap());
+        let mut stream = streaming_deserializer::create(s.as_bytes(), None)
+            .map(|r: crate::Result<Ruling>| r.unwrap());
 
         while let Some(r) = stream.next().await {
             drop(r)
         }
     }
+
+    #[test]
+    fn scans_borrowed_cards() {
+        use serde_json::json;
+
+        use crate::util::test_card::card_json;
+
+        let json = json!([card_json(json!({})), card_json(json!({ "name": "Shock" }))]).to_string();
+        let mut names = Vec::new();
+        super::for_each_card_ref(json.as_bytes(), |card| names.push(card.name)).unwrap();
+        assert_eq!(names, ["Lightning Bolt", "Shock"]);
+
+        assert!(super::for_each_card_ref(b"[{}]", |_| {}).is_err());
+        assert!(super::for_each_card_ref(b"[] trailing", |_| {}).is_err());
+    }
+
+    /// Downloads a bulk data file from made up responses, not ones recorded
+    /// from Scryfall. The ignored tests above check the real API.
+    #[tokio::test]
+    async fn downloads_a_synthetic_bulk_file() {
+        use serde_json::json;
+
+        use crate::bulk::BulkDataFile;
+        use crate::client::RateLimiter;
+        use crate::util::test_card::card_json;
+        use crate::util::test_server::TestServer;
+        use crate::{Card, ScryfallClient};
+
+        let file = json!({
+            "object": "bulk_data",
+            "id": "27bf3214-1271-490b-bdfe-c0be6c23d02e",
+            "type": "oracle_cards",
+            "updated_at": "2025-01-01T10:04:41.282+00:00",
+            "uri": "https://api.scryfall.com/bulk-data/oracle-cards",
+            "name": "Oracle Cards",
+            "description": "A JSON file containing one Scryfall card object for each Oracle ID.",
+            "size": 2048,
+            "download_uri": "https://data.scryfall.io/oracle-cards/oracle-cards.json",
+            "content_type": "application/json",
+            "content_encoding": "gzip",
+        });
+        let cards = json!([card_json(json!({})), card_json(json!({ "name": "Shock" }))]);
+        let server =
+            TestServer::start(vec![(200, file.to_string()), (200, cards.to_string())]).await;
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .build()
+            .unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "scryfall-synthetic-bulk-{}.json",
+            std::process::id()
+        ));
+        client
+            .scope(async {
+                let file = BulkDataFile::<Card>::of_type("oracle_cards").await.unwrap();
+                assert_eq!(file.size, 2048);
+                file.download(&path).await.unwrap();
+            })
+            .await;
+        let json = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let cards: Vec<Card> = streaming_deserializer::collect(json.as_slice(), None).unwrap();
+        assert_eq!(
+            cards
+                .iter()
+                .map(|card| card.name.as_str())
+                .collect::<Vec<_>>(),
+            ["Lightning Bolt", "Shock"]
+        );
+        assert!(server.requests()[1].starts_with("GET /oracle-cards/oracle-cards.json"));
+    }
 }
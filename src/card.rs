@@ -5,7 +5,12 @@
 //! documented in the official [scryfall page](https://scryfall.com/docs/api/cards).
 mod border_color;
 mod card_faces;
+mod card_ref;
+mod card_store;
+mod collection;
+mod collector_number;
 mod color;
+mod face_view;
 mod finishes;
 mod frame;
 mod frame_effect;
@@ -23,8 +28,8 @@ mod rarity;
 mod related_card;
 mod related_uris;
 mod security_stamp;
-
-use std::ops::{Index, IndexMut};
+mod stat_value;
+mod type_line;
 
 use chrono::NaiveDate;
 use purchase_uris::PurchaseUris;
@@ -35,162 +40,45 @@ use uuid::Uuid;
 
 pub use self::border_color::BorderColor;
 pub use self::card_faces::CardFace;
+pub use self::card_ref::CardRef;
+pub use self::card_store::CardStore;
+use self::collection::CollectionPage;
+pub use self::collection::{CardCollection, CardIdentifier};
+pub use self::collector_number::CollectorNumber;
 pub use self::color::{Color, Colors, Multicolored};
+pub use self::face_view::FaceView;
 pub use self::finishes::Finishes;
 pub use self::frame::Frame;
 pub use self::frame_effect::FrameEffect;
 pub use self::game::Game;
 pub use self::image_status::ImageStatus;
+pub use self::languages::Languages;
 pub use self::layout::Layout;
-pub use self::legality::Legality;
+pub use self::legality::{CardLegality, Legality};
 pub use self::preview::Preview;
-pub use self::price::Price;
+pub use self::price::{Currency, Money, ParseMoneyError, Price};
 pub use self::produced_mana::{ProducedMana, UnfinityMana};
 pub use self::promo_types::PromoType;
 pub use self::rarity::Rarity;
 pub use self::related_card::Component;
 pub use self::related_card::RelatedCard;
 pub use self::security_stamp::SecurityStamp;
-use crate::format::Format;
+pub use self::stat_value::{ParseStatValueError, StatValue};
+pub use self::type_line::{
+    CardType, FaceTypes, ParseTypeLineError, SubtypeCatalog, Supertype, TypeLine,
+};
+use crate::catalog::Catalog;
+use crate::client::ScryfallClient;
 use crate::list::{List, ListIter};
+use crate::oracle::{Ability, Tokenizer};
 use crate::ruling::Ruling;
 use crate::search::Search;
 use crate::set::{Set, SetCode, SetType};
+use crate::symbology::{ManaCost, ParseManaCostError};
 use crate::uri::Uri;
 use crate::util::CARDS_URL;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
-#[cfg_attr(not(feature = "unknown_variants"), derive(Copy))]
-#[cfg_attr(
-    all(
-        not(feature = "unknown_variants"),
-        not(feature = "unknown_variants_slim")
-    ),
-    non_exhaustive
-)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
-#[allow(missing_docs)]
-pub struct CardLegality {
-    #[serde(default)]
-    pub standard: Legality,
-    #[serde(default)]
-    pub modern: Legality,
-    #[serde(default)]
-    pub legacy: Legality,
-    #[serde(default)]
-    pub vintage: Legality,
-    #[serde(default)]
-    pub commander: Legality,
-    #[serde(default)]
-    pub future: Legality,
-    #[serde(default)]
-    pub pauper: Legality,
-    #[serde(default)]
-    pub pioneer: Legality,
-    #[serde(default)]
-    pub penny: Legality,
-    #[serde(default)]
-    pub duel: Legality,
-    #[serde(default, rename = "oldschool")]
-    pub old_school: Legality,
-    #[serde(default)]
-    pub historic: Legality,
-    #[serde(default)]
-    pub gladiator: Legality,
-    #[serde(default)]
-    pub brawl: Legality,
-    #[serde(default)]
-    pub premodern: Legality,
-    #[serde(default, rename = "paupercommander")]
-    pub pauper_commander: Legality,
-    #[serde(default)]
-    pub alchemy: Legality,
-    #[serde(default)]
-    pub explorer: Legality,
-    #[serde(default)]
-    pub predh: Legality,
-    #[serde(default)]
-    pub oathbreaker: Legality,
-    #[serde(default)]
-    pub timeless: Legality,
-    #[serde(default, rename = "standardbrawl")]
-    pub standard_brawl: Legality,
-    #[serde(default, rename = "historicbrawl")]
-    pub historic_brawl: Legality,
-    #[serde(default, rename = "competitivebrawl")]
-    pub competitive_brawl: Legality,
-    #[serde(default)]
-    pub tlr: Legality,
-}
-
-impl Index<Format> for CardLegality {
-    type Output = Legality;
-
-    fn index(&self, index: Format) -> &Self::Output {
-        match index {
-            Format::Standard => &self.standard,
-            Format::Modern => &self.modern,
-            Format::Legacy => &self.legacy,
-            Format::Vintage => &self.vintage,
-            Format::Commander => &self.commander,
-            Format::Future => &self.future,
-            Format::Pauper => &self.pauper,
-            Format::Pioneer => &self.pioneer,
-            Format::Penny => &self.penny,
-            Format::Duel => &self.duel,
-            Format::OldSchool => &self.old_school,
-            Format::Historic => &self.historic,
-            Format::Gladiator => &self.gladiator,
-            Format::Brawl => &self.brawl,
-            Format::Premodern => &self.premodern,
-            Format::PauperCommander => &self.pauper_commander,
-            Format::Alchemy => &self.alchemy,
-            Format::Explorer => &self.explorer,
-            Format::Predh => &self.predh,
-            Format::Oathbreaker => &self.oathbreaker,
-            Format::Timeless => &self.timeless,
-            Format::StandardBrawl => &self.standard_brawl,
-            Format::HistoricBrawl => &self.historic_brawl,
-            Format::CompetitiveBrawl => &self.competitive_brawl,
-            Format::TLR => &self.tlr,
-        }
-    }
-}
-
-impl IndexMut<Format> for CardLegality {
-    fn index_mut(&mut self, index: Format) -> &mut Self::Output {
-        match index {
-            Format::Standard => &mut self.standard,
-            Format::Modern => &mut self.modern,
-            Format::Legacy => &mut self.legacy,
-            Format::Vintage => &mut self.vintage,
-            Format::Commander => &mut self.commander,
-            Format::Future => &mut self.future,
-            Format::Pauper => &mut self.pauper,
-            Format::Pioneer => &mut self.pioneer,
-            Format::Penny => &mut self.penny,
-            Format::Duel => &mut self.duel,
-            Format::OldSchool => &mut self.old_school,
-            Format::Historic => &mut self.historic,
-            Format::Gladiator => &mut self.gladiator,
-            Format::Brawl => &mut self.brawl,
-            Format::Premodern => &mut self.premodern,
-            Format::PauperCommander => &mut self.pauper_commander,
-            Format::Alchemy => &mut self.alchemy,
-            Format::Explorer => &mut self.explorer,
-            Format::Predh => &mut self.predh,
-            Format::Oathbreaker => &mut self.oathbreaker,
-            Format::Timeless => &mut self.timeless,
-            Format::StandardBrawl => &mut self.standard_brawl,
-            Format::HistoricBrawl => &mut self.historic_brawl,
-            Format::CompetitiveBrawl => &mut self.competitive_brawl,
-            Format::TLR => &mut self.tlr,
-        }
-    }
-}
-
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 /// Scryfall produces multiple sizes of images and image crops for each Card object. Links to these
 /// images are available in each Card objects’ image_uris properties.
 ///
@@ -206,24 +94,57 @@ pub struct ImageUris {
     /// A transparent, rounded full card PNG. This is the best image to use for videos or other
     /// high-quality content.
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub png: Option<Url>,
     /// A full card image with the rounded corners and the majority of the border cropped off.
     /// Designed for dated contexts where rounded images can’t be used.
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub border_crop: Option<Url>,
     /// A rectangular crop of the card’s art only. Not guaranteed to be perfect for cards with
     /// outlier designs or strange frame arrangements
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub art_crop: Option<Url>,
     ///  A large full card image
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub large: Option<Url>,
     /// A medium-sized full card image
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub normal: Option<Url>,
     /// A small full card image. Designed for use as thumbnail or list icon.
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub small: Option<Url>,
+
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<ImageUris, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
 }
 
 /// Card objects represent individual Magic: The Gathering cards that players
@@ -250,52 +171,86 @@ pub struct ImageUris {
 ///
 /// For more details, see the [official documentation](https://scryfall.com/docs/api/cards).
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Card {
     // region Core Card Fields
     // =======================
     /// This card’s Arena ID, if any. A large percentage of cards are not
     /// available on Arena and do not have this ID.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub arena_id: Option<usize>,
 
     /// A unique ID for this card in Scryfall’s database.
     pub id: Uuid,
 
-    // TODO(msmorgan): Language enum? https://scryfall.com/docs/api/languages
-    /// A language code for this printing.
-    pub lang: String,
+    /// The language of this printing.
+    pub lang: Languages,
 
     /// This card’s Magic Online ID (also known as the Catalog ID), if any. A
     /// large percentage of cards are not available on Magic Online and do not
     /// have this ID.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub mtgo_id: Option<usize>,
 
     /// This card’s foil Magic Online ID (also known as the Catalog ID), if any.
     /// A large percentage of cards are not available on Magic Online and do not
     /// have this ID.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub mtgo_foil_id: Option<usize>,
 
     /// This card’s multiverse IDs on Gatherer, if any, as an array of integers.
     /// Note that Scryfall includes many promo cards, tokens, and other esoteric
     /// objects that do not have these identifiers.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub multiverse_ids: Option<Vec<usize>>,
 
     /// This card’s ID on TCGplayer’s API, also known as the `productId`.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub tcgplayer_id: Option<usize>,
 
     /// This card’s ID on TCGplayer’s API, for its etched version if that version is a separate product.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub tcgplayer_etched_id: Option<usize>,
 
     /// This card’s ID on Cardmarket’s API, also known as the `idProduct`.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub cardmarket_id: Option<usize>,
 
     /// A unique ID for this card’s oracle identity. This value is consistent
     /// across reprinted card editions, and unique among different cards with
     /// the same name (tokens, Unstable variants, etc).
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub oracle_id: Option<Uuid>,
 
     /// This card’s Resource ID on Gatherer, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub resource_id: Option<String>,
 
     /// A link to where you can begin paginating all re/prints for this card on
@@ -317,13 +272,25 @@ pub struct Card {
     // ======================
     /// If this card is closely related to other cards, this property will be an
     /// array with Related Card Objects.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub all_parts: Option<Vec<RelatedCard>>,
 
     /// An array of Card Face objects, if this card is multifaced.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub card_faces: Option<Vec<CardFace>>,
 
     /// The card’s converted mana cost. Note that some funny cards have
     /// fractional mana costs.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub cmc: Option<f32>,
 
     /// This card’s color identity.
@@ -331,16 +298,32 @@ pub struct Card {
 
     /// The colors in this card’s color indicator, if any. A null value for this
     /// field indicates the card does not have one.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub color_indicator: Option<Vec<Color>>,
 
     /// This card’s colors, if the overall card has colors defined by the rules.
     /// Otherwise the colors will be on the card_faces objects, see below.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub colors: Option<Vec<Color>>,
 
     /// This card’s overall rank/popularity on EDHREC. Not all cards are ranked.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub edhrec_rank: Option<usize>,
 
     /// True if this card is on the Commander Game Changer list.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub game_changer: Option<bool>,
 
     /// True if this printing exists in a foil version.
@@ -348,6 +331,10 @@ pub struct Card {
 
     /// This card’s hand modifier, if it is Vanguard card. This value will
     /// contain a delta, such as -1.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub hand_modifier: Option<String>,
 
     /// An array of keywords that this card uses, such as 'Flying' and
@@ -363,16 +350,28 @@ pub struct Card {
 
     /// This card’s life modifier, if it is Vanguard card. This value will
     /// contain a delta, such as +2.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub life_modifier: Option<String>,
 
     /// This loyalty if any. Note that some cards have loyalties that are not
     /// numeric, such as X.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub loyalty: Option<String>,
 
     /// The mana cost for this card. This value will be any empty string "" if
     /// the cost is absent. Remember that per the game rules, a missing mana
     /// cost and a mana cost of {0} are different values. Multi-faced cards will
     /// report this value in card faces.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub mana_cost: Option<String>,
 
     /// The name of this card. If this card has multiple faces, this field will
@@ -383,6 +382,10 @@ pub struct Card {
     pub nonfoil: bool,
 
     /// The Oracle text for this card, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub oracle_text: Option<String>,
 
     /// True if this card is oversized.
@@ -390,9 +393,17 @@ pub struct Card {
 
     /// This card’s power, if any. Note that some cards have powers that are not
     /// numeric, such as *.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub power: Option<String>,
 
     /// Colors of mana that this card could produce.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub produced_mana: Option<Vec<ProducedMana>>,
 
     /// True if this card is on the Reserved List.
@@ -400,15 +411,31 @@ pub struct Card {
 
     /// This card’s toughness, if any. Note that some cards have toughnesses
     /// that are not numeric, such as *.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub toughness: Option<String>,
 
     /// The type line of this card.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub type_line: Option<String>,
 
     /// This card’s rank/popularity on Penny Dreadful. Not all cards are ranked.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub penny_rank: Option<u64>,
 
     /// This face’s defense, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub defense: Option<String>,
     // =========================
     // endregion Gameplay Fields
@@ -417,9 +444,17 @@ pub struct Card {
     // ===================
     /// The name of the illustrator of this card. Newly spoiled cards may not
     /// have this field yet.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub artist: Option<String>,
 
     /// The IDs of the artists that illustrated this card. Newly spoiled cards may not have this field yet.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub artist_ids: Option<Vec<Uuid>>,
 
     /// Whether this card is found in boosters.
@@ -429,14 +464,22 @@ pub struct Card {
     pub border_color: BorderColor,
 
     /// The Scryfall ID for the card back design present on this card.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub card_back_id: Option<Uuid>,
 
     /// This card’s collector number. Note that collector numbers can contain
     /// non-numeric characters, such as letters or `★`.
-    pub collector_number: String,
+    pub collector_number: CollectorNumber,
 
     /// True if you should consider avoiding use of this print downstream.
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "crate::util::is_default")
+    )]
     pub content_warning: bool,
 
     /// True if this card was only released in a video game.
@@ -444,13 +487,25 @@ pub struct Card {
 
     /// The just-for-fun name printed on the card (such as for Godzilla series
     /// cards).
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub flavor_name: Option<String>,
 
     /// The flavor text, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub flavor_text: Option<String>,
 
     /// This card’s frame effects, if any.
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "crate::util::is_default")
+    )]
     pub frame_effects: Vec<FrameEffect>,
 
     /// This card’s frame layout.
@@ -468,6 +523,10 @@ pub struct Card {
 
     /// A unique identifier for the card artwork that remains consistent across
     /// reprints. Newly spoiled cards may not have this field yet.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub illustration_id: Option<Uuid>,
 
     /// A computer-readable indicator for the state of this card’s image,
@@ -476,6 +535,10 @@ pub struct Card {
 
     /// An object listing available imagery for this card. See the [Card Imagery](https://scryfall.com/docs/api/images) article for more information.
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub image_uris: Option<ImageUris>,
 
     /// An object containing daily price information for this card, including
@@ -484,18 +547,34 @@ pub struct Card {
     pub prices: Price,
 
     /// The localized name printed on this card, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub printed_name: Option<String>,
 
     /// The localized text printed on this card, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub printed_text: Option<String>,
 
     /// The localized type line printed on this card, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub printed_type_line: Option<String>,
 
     /// True if this card is a promotional print.
     pub promo: bool,
 
     /// An object providing URIs to this card’s listing on major marketplaces.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub purchase_uris: Option<PurchaseUris>,
 
     /// This card’s rarity. One of `common`, `uncommon`, `rare`, or `mythic`.
@@ -503,6 +582,10 @@ pub struct Card {
 
     /// An object providing URIs to this card’s listing on other Magic: The
     /// Gathering online resources.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub related_uris: Option<RelatedUris>,
 
     /// The date this card was first released.
@@ -543,13 +626,25 @@ pub struct Card {
     pub variation: bool,
 
     /// The printing ID of the printing this card is a variation of.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub variation_of: Option<Uuid>,
 
     /// This card’s watermark, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub watermark: Option<String>,
 
     /// Information about when and where the card was originally previewed.
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "crate::util::is_default")
+    )]
     pub preview: Preview,
 
     /// The finishes the card can come in.
@@ -557,18 +652,39 @@ pub struct Card {
 
     /// The security stamp on this card, if any.
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub security_stamp: Option<SecurityStamp>,
 
     /// An array of strings describing what categories of promo cards this card falls into.
     #[serde(default)]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "crate::util::is_default")
+    )]
     pub promo_types: Vec<PromoType>,
 
     /// The lit Unfinity attractions lights on this card, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub attraction_lights: Option<Vec<u8>>,
     /* ======================
      * endregion Print Fields */
     /// fooo
     pub image_updated_at: String,
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<Card, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
+
     #[cfg(test)]
     #[serde(rename = "object")]
     _object: String,
@@ -769,7 +885,46 @@ impl Card {
         Uri::from(url).fetch().await
     }
 
-    /// Fetch a card by its set and number.
+    /// Returns a [`Catalog`] of up to 20 full English card names that could be
+    /// autocompletions of `query`, for example to suggest names as a user is
+    /// typing them. If `include_extras` is true, extra cards such as tokens,
+    /// planes and schemes are suggested too.
+    ///
+    /// Queries shorter than 2 letters have no suggestions. See
+    /// [`Catalog::autocomplete`] for doing this offline.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// # tokio_test::block_on(async {
+    /// let suggestions = Card::autocomplete("thal", false).await.unwrap();
+    /// assert!(suggestions.data.iter().any(|name| name == "Thalia, Guardian of Thraben"));
+    /// # })
+    /// ```
+    pub async fn autocomplete(query: &str, include_extras: bool) -> crate::Result<Catalog> {
+        /// The autocomplete catalog, which unlike the others has no uri.
+        #[derive(Deserialize)]
+        struct Suggestions {
+            total_values: usize,
+            data: Vec<String>,
+        }
+
+        let mut url = CARDS_URL.join("autocomplete")?;
+        url.query_pairs_mut().append_pair("q", query);
+        if include_extras {
+            url.query_pairs_mut().append_pair("include_extras", "true");
+        }
+        let suggestions: Suggestions = Uri::from(url.clone()).fetch().await?;
+        Ok(Catalog {
+            uri: Uri::from(url),
+            total_values: suggestions.total_values,
+            data: suggestions.data,
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        })
+    }
+
+    /// Fetch a card by its set and collector number.
     ///
     /// # Examples
     /// ```rust
@@ -781,12 +936,74 @@ impl Card {
     /// }
     /// # })
     /// ```
-    pub async fn set_and_number(set_code: &str, number: usize) -> crate::Result<Card> {
+    ///
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// # tokio_test::block_on(async {
+    /// let card = Card::set_and_number("sld", "2021-7").await.unwrap();
+    /// assert_eq!(card.collector_number, "2021-7");
+    /// # })
+    /// ```
+    pub async fn set_and_number(
+        set_code: &str,
+        number: impl Into<CollectorNumber>,
+    ) -> crate::Result<Card> {
+        let number = number.into();
         Uri::from(CARDS_URL.join(&format!("{set_code}/{number}"))?)
             .fetch()
             .await
     }
 
+    /// Fetch the printing of a card in a language, by its set and collector
+    /// number.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Languages};
+    /// # tokio_test::block_on(async {
+    /// let card = Card::set_number_and_language("war", 97, Languages::Japanese)
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(card.name, "Ob Nixilis's Cruelty");
+    /// assert_eq!(card.lang, Languages::Japanese);
+    /// # })
+    /// ```
+    pub async fn set_number_and_language(
+        set_code: &str,
+        number: impl Into<CollectorNumber>,
+        language: Languages,
+    ) -> crate::Result<Card> {
+        let number = number.into();
+        Uri::from(CARDS_URL.join(&format!(
+            "{set_code}/{number}/{}",
+            language.as_country_code()
+        ))?)
+        .fetch()
+        .await
+    }
+
+    /// Fetch this printing in another language, if it was printed in it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Languages};
+    /// # tokio_test::block_on(async {
+    /// let card = Card::set_and_number("war", 97).await.unwrap();
+    /// let japanese = card.in_language(Languages::Japanese).await.unwrap();
+    /// assert_eq!(japanese.oracle_id, card.oracle_id);
+    /// assert_eq!(japanese.lang, Languages::Japanese);
+    /// # })
+    /// ```
+    pub async fn in_language(&self, language: Languages) -> crate::Result<Card> {
+        Card::set_number_and_language(self.set.get(), self.collector_number.clone(), language).await
+    }
+
+    /// Fetch the English version of this printing. Cards that were never
+    /// printed in English, like some Japanese promos, have none.
+    pub async fn in_english(&self) -> crate::Result<Card> {
+        self.in_language(Languages::English).await
+    }
+
     /// Fetch a card by its multiverse id.
     ///
     /// # Examples
@@ -884,4 +1101,293 @@ impl Card {
             .fetch()
             .await
     }
+
+    /// Parses the mana cost of this card, returning `None` if it has no mana
+    /// cost.
+    ///
+    /// Cards with several faces that are cast separately, such as split
+    /// cards, report the costs of all their faces here, and their symbols are
+    /// all included in the result. Use [`CardFace::parse_mana_cost`] to get
+    /// the cost of each face.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Colors};
+    /// # tokio_test::block_on(async {
+    /// let card = Card::named("Kolaghan's Command").await.unwrap();
+    /// let cost = card.parse_mana_cost().unwrap().unwrap();
+    /// assert_eq!(cost.mana_value(), 3.0);
+    /// assert_eq!(cost.colors(), Colors::RAKDOS);
+    /// # })
+    /// ```
+    pub fn parse_mana_cost(&self) -> Result<Option<ManaCost>, ParseManaCostError> {
+        let Some(cost) = self.mana_cost.as_deref().filter(|cost| !cost.is_empty()) else {
+            return Ok(None);
+        };
+        let mut symbols = Vec::new();
+        for face in cost.split(" // ") {
+            symbols.extend_from_slice(face.parse::<ManaCost>()?.symbols());
+        }
+        Ok(Some(ManaCost::new(symbols)))
+    }
+
+    /// Parses the power of this card, returning `None` if it has none.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::Card;
+    /// # tokio_test::block_on(async {
+    /// let card = Card::named("Tarmogoyf").await.unwrap();
+    /// let power = card.parse_power().unwrap().unwrap();
+    /// assert_eq!(power.to_string(), "*");
+    /// assert_eq!(power.value(), 0.0);
+    /// # })
+    /// ```
+    pub fn parse_power(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.power.as_deref().map(str::parse).transpose()
+    }
+
+    /// Parses the toughness of this card, returning `None` if it has none.
+    pub fn parse_toughness(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.toughness.as_deref().map(str::parse).transpose()
+    }
+
+    /// Parses the loyalty of this card, returning `None` if it has none.
+    pub fn parse_loyalty(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.loyalty.as_deref().map(str::parse).transpose()
+    }
+
+    /// Parses the defense of this card, returning `None` if it has none.
+    pub fn parse_defense(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.defense.as_deref().map(str::parse).transpose()
+    }
+
+    /// Splits the Oracle text of this card into abilities, with
+    /// [`Tokenizer::for_card`]. The abilities of every face are included, in
+    /// order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::oracle::AbilityKind;
+    /// use scryfall::Card;
+    /// # tokio_test::block_on(async {
+    /// let card = Card::named("Serra Angel").await.unwrap();
+    /// let abilities = card.oracle_abilities();
+    /// assert_eq!(abilities[0].kind, AbilityKind::Keyword);
+    /// # })
+    /// ```
+    pub fn oracle_abilities(&self) -> Vec<Ability<'_>> {
+        let tokenizer = Tokenizer::for_card(self);
+        match &self.oracle_text {
+            Some(text) => tokenizer.tokenize(text),
+            None => self
+                .card_faces
+                .iter()
+                .flatten()
+                .filter_map(|face| face.oracle_text.as_deref())
+                .flat_map(|text| tokenizer.tokenize(text))
+                .collect(),
+        }
+    }
+
+    /// A view of each face of this card, in order. Single faced cards have a
+    /// single face, made from the card itself.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::Card;
+    /// # tokio_test::block_on(async {
+    /// let card = Card::named("Fire // Ice").await.unwrap();
+    /// let names = card.faces().iter().map(|face| face.name).collect::<Vec<_>>();
+    /// assert_eq!(names, ["Fire", "Ice"]);
+    /// # })
+    /// ```
+    pub fn faces(&self) -> Vec<FaceView<'_>> {
+        match self.card_faces.as_deref() {
+            Some(faces) if !faces.is_empty() => faces
+                .iter()
+                .map(|face| FaceView::from_face(self, face))
+                .collect(),
+            _ => vec![FaceView::from_card(self)],
+        }
+    }
+
+    /// The face on the front of this card. For cards whose faces are all on
+    /// the front, such as split cards, this is the first one.
+    pub fn front(&self) -> FaceView<'_> {
+        match self.card_faces.as_deref() {
+            Some([front, ..]) => FaceView::from_face(self, front),
+            _ => FaceView::from_card(self),
+        }
+    }
+
+    /// The face on the back of this card, if its [`layout`](Self::layout) is
+    /// [double sided](Layout::is_double_sided).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::Card;
+    /// # tokio_test::block_on(async {
+    /// let card = Card::named("Delver of Secrets").await.unwrap();
+    /// assert_eq!(card.front().name, "Delver of Secrets");
+    /// assert_eq!(card.back().unwrap().name, "Insectile Aberration");
+    /// # })
+    /// ```
+    pub fn back(&self) -> Option<FaceView<'_>> {
+        if !self.layout.is_double_sided() {
+            return None;
+        }
+        match self.card_faces.as_deref() {
+            Some([_, back, ..]) => Some(FaceView::from_face(self, back)),
+            _ => None,
+        }
+    }
+
+    /// The finish of this card that's cheapest in `currency`, and its price.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, Currency};
+    /// # tokio_test::block_on(async {
+    /// let card = Card::named("Lightning Bolt").await.unwrap();
+    /// if let Some((finish, price)) = card.cheapest_price(Currency::Usd) {
+    ///     println!("{} costs {} in {:?}", card.name, price, finish);
+    /// }
+    /// # })
+    /// ```
+    pub fn cheapest_price(&self, currency: Currency) -> Option<(Finishes, Money)> {
+        self.prices
+            .cheapest(currency, self.finishes.iter().cloned())
+    }
+
+    /// Parses the type line of this card, returning `None` if it has none.
+    ///
+    /// Cards whose faces each have their own type line, such as reversible
+    /// cards, have the type lines of their faces joined.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, CardType};
+    /// # tokio_test::block_on(async {
+    /// let card = Card::named("Fire // Ice").await.unwrap();
+    /// let type_line = card.parse_type_line().unwrap().unwrap();
+    /// assert_eq!(type_line.faces().len(), 2);
+    /// assert!(type_line.has_type(CardType::Instant));
+    /// # })
+    /// ```
+    pub fn parse_type_line(&self) -> Result<Option<TypeLine>, ParseTypeLineError> {
+        if let Some(type_line) = &self.type_line {
+            return type_line.parse().map(Some);
+        }
+        let faces = self
+            .card_faces
+            .iter()
+            .flatten()
+            .filter_map(|face| face.type_line.as_deref())
+            .collect::<Vec<_>>();
+        if faces.is_empty() {
+            return Ok(None);
+        }
+        faces.join(" // ").parse().map(Some)
+    }
+
+    /// Returns true if any face of this card has the given card type. Cards
+    /// whose type line can't be parsed have no types.
+    pub fn has_type(&self, card_type: CardType) -> bool {
+        self.parsed_type_line()
+            .is_some_and(|type_line| type_line.has_type(card_type))
+    }
+
+    /// Returns true if any face of this card has the given supertype.
+    pub fn has_supertype(&self, supertype: Supertype) -> bool {
+        self.parsed_type_line()
+            .is_some_and(|type_line| type_line.has_supertype(supertype))
+    }
+
+    /// Returns true if any face of this card has the given subtype. The
+    /// comparison ignores case.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::Card;
+    /// # tokio_test::block_on(async {
+    /// let card = Card::named("Goblin Guide").await.unwrap();
+    /// assert!(card.is_creature() && card.has_subtype("Goblin"));
+    /// # })
+    /// ```
+    pub fn has_subtype(&self, subtype: &str) -> bool {
+        self.parsed_type_line()
+            .is_some_and(|type_line| type_line.has_subtype(subtype))
+    }
+
+    /// Returns true if this card is a creature.
+    pub fn is_creature(&self) -> bool {
+        self.has_type(CardType::Creature)
+    }
+
+    /// Returns true if this card is a land.
+    pub fn is_land(&self) -> bool {
+        self.has_type(CardType::Land)
+    }
+
+    /// Returns true if this card is legendary.
+    pub fn is_legendary(&self) -> bool {
+        self.has_supertype(Supertype::Legendary)
+    }
+
+    fn parsed_type_line(&self) -> Option<TypeLine> {
+        self.parse_type_line().ok().flatten()
+    }
+
+    /// Fetches many cards at once, given a list of identifiers.
+    ///
+    /// Scryfall accepts up to 75 identifiers per request, so longer lists are
+    /// split into several requests. The cards found are returned in the order
+    /// of the identifiers that matched them, and the identifiers that didn't
+    /// match any card are returned separately.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, CardIdentifier};
+    /// # tokio_test::block_on(async {
+    /// let collection = Card::collection(vec![
+    ///     CardIdentifier::Name("Lightning Bolt".to_string()),
+    ///     CardIdentifier::MtgoId(54957),
+    ///     CardIdentifier::Name("Not A Real Card".to_string()),
+    /// ])
+    /// .await
+    /// .unwrap();
+    /// let names = collection.data.iter().map(|c| &c.name).collect::<Vec<_>>();
+    /// assert_eq!(names, ["Lightning Bolt", "Ghost Quarter"]);
+    /// assert_eq!(
+    ///     collection.not_found,
+    ///     [CardIdentifier::Name("Not A Real Card".to_string())]
+    /// );
+    /// # })
+    /// ```
+    pub async fn collection(
+        identifiers: impl IntoIterator<Item = CardIdentifier>,
+    ) -> crate::Result<CardCollection> {
+        const MAX_IDENTIFIERS: usize = 75;
+
+        #[derive(Serialize)]
+        struct Request<'a> {
+            identifiers: &'a [CardIdentifier],
+        }
+
+        let identifiers = identifiers.into_iter().collect::<Vec<_>>();
+        let url = CARDS_URL.join("collection")?;
+        let client = ScryfallClient::current();
+        let mut collection = CardCollection::default();
+        for chunk in identifiers.chunks(MAX_IDENTIFIERS) {
+            let page: CollectionPage = client
+                .post_json(&url, &Request { identifiers: chunk })
+                .await?
+                .json(client.schema_drift())
+                .await?;
+            collection.data.extend(page.data);
+            collection.not_found.extend(page.not_found);
+        }
+        Ok(collection)
+    }
 }
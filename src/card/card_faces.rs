@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::card::Color;
-use crate::card::ImageUris;
+use crate::card::{ImageUris, ParseStatValueError, ParseTypeLineError, StatValue, TypeLine};
+use crate::symbology::{ManaCost, ParseManaCostError};
 
 use super::Layout;
 
@@ -14,35 +15,66 @@ use super::Layout;
 /// For more information, refer to the [official docs](https://scryfall.com/docs/api/cards#card-face-objects).
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 #[non_exhaustive]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct CardFace {
     /// The name of the illustrator of this card face. Newly spoiled cards may
     /// not have this field yet.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub artist: Option<String>,
 
     /// The colors in this face’s color indicator, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub color_indicator: Option<Vec<Color>>,
 
     /// This face’s colors, if the game defines colors for the individual face
     /// of this card.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub colors: Option<Vec<Color>>,
 
     /// The mana value of this particular face, if the card is reversible.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub cmc: Option<f32>,
 
     /// The flavor text printed on this face, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub flavor_text: Option<String>,
 
     /// A unique identifier for the card face artwork that remains consistent
     /// across reprints. Newly spoiled cards may not have this field yet.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub illustration_id: Option<Uuid>,
 
     /// An object providing URIs to imagery for this face, if this is a
     /// double-sided card. If this card is not double-sided, then the image_uris
     /// property will be part of the parent object instead.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub image_uris: Option<ImageUris>,
 
     /// This face’s loyalty, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub loyalty: Option<String>,
 
     /// The mana cost for this face. This value will be any empty string "" if
@@ -54,47 +86,177 @@ pub struct CardFace {
     pub name: String,
 
     /// The Oracle ID of this particular face, if the card is reversible.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub oracle_id: Option<Uuid>,
 
     /// The Oracle text for this face, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub oracle_text: Option<String>,
 
     /// This face’s power, if any. Note that some cards have powers that are not
     /// numeric, such as `*`.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub power: Option<String>,
 
     /// The localized name printed on this face, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub printed_name: Option<String>,
 
     /// The localized text printed on this face, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub printed_text: Option<String>,
 
     /// The type line as printed on the card.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub printed_type_line: Option<String>,
 
     /// This face’s toughness, if any. Note that some cards have powers that are not
     /// numeric, such as `*`.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub toughness: Option<String>,
 
     /// The type line of this particular face.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub type_line: Option<String>,
 
     /// The watermark on this particulary card face, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub watermark: Option<String>,
 
     /// The ID of the illustrator of this card face. Newly spoiled cards may not have this field yet.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub artist_id: Option<Uuid>,
 
     /// The just-for-fun name printed on the card (such as for Godzilla series cards).
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub flavor_name: Option<String>,
 
     /// This face’s defense, if it's a battle.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub defense: Option<String>,
 
     /// The layout of this card face, if the card is reversible.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub layout: Option<Layout>,
 
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<CardFace, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
+
     #[cfg(test)]
     #[serde(rename = "object")]
     _object: String,
 }
+
+impl CardFace {
+    /// Parses the mana cost of this face, returning `None` if it has no
+    /// mana cost.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::Card;
+    /// # tokio_test::block_on(async {
+    /// let fire_ice = Card::named("Fire // Ice").await.unwrap();
+    /// let faces = fire_ice.card_faces.unwrap();
+    /// let ice = faces[1].parse_mana_cost().unwrap().unwrap();
+    /// assert_eq!(ice.to_string(), "{1}{U}");
+    /// # })
+    /// ```
+    pub fn parse_mana_cost(&self) -> Result<Option<ManaCost>, ParseManaCostError> {
+        if self.mana_cost.is_empty() {
+            return Ok(None);
+        }
+        self.mana_cost.parse().map(Some)
+    }
+
+    /// Parses the power of this face, returning `None` if it has none.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::Card;
+    /// # tokio_test::block_on(async {
+    /// let delver = Card::named("Delver of Secrets").await.unwrap();
+    /// let faces = delver.card_faces.unwrap();
+    /// assert_eq!(faces[1].parse_power().unwrap().unwrap().value(), 3.0);
+    /// # })
+    /// ```
+    pub fn parse_power(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.power.as_deref().map(str::parse).transpose()
+    }
+
+    /// Parses the toughness of this face, returning `None` if it has none.
+    pub fn parse_toughness(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.toughness.as_deref().map(str::parse).transpose()
+    }
+
+    /// Parses the loyalty of this face, returning `None` if it has none.
+    pub fn parse_loyalty(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.loyalty.as_deref().map(str::parse).transpose()
+    }
+
+    /// Parses the defense of this face, returning `None` if it has none.
+    pub fn parse_defense(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.defense.as_deref().map(str::parse).transpose()
+    }
+
+    /// Parses the type line of this face, returning `None` if it has none.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::CardType;
+    /// use scryfall::Card;
+    /// # tokio_test::block_on(async {
+    /// let delver = Card::named("Delver of Secrets").await.unwrap();
+    /// let faces = delver.card_faces.unwrap();
+    /// let flyer = faces[1].parse_type_line().unwrap().unwrap();
+    /// assert!(flyer.has_type(CardType::Creature) && flyer.has_subtype("Faerie"));
+    /// # })
+    /// ```
+    pub fn parse_type_line(&self) -> Result<Option<TypeLine>, ParseTypeLineError> {
+        self.type_line.as_deref().map(str::parse).transpose()
+    }
+}
@@ -0,0 +1,444 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::fmt;
+
+use chrono::NaiveDate;
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use url::Url;
+use uuid::Uuid;
+
+use crate::card::{
+    BorderColor, Card, CardLegality, CollectorNumber, Color, Finishes, Frame, FrameEffect, Game,
+    ImageStatus, Languages, Layout, ProducedMana, PromoType, Rarity, SecurityStamp,
+};
+use crate::set::{SetCode, SetType};
+use crate::uri::Uri;
+
+/// A card that borrows its text from the json it was deserialized from.
+///
+/// Deserializing a [`Card`] allocates every string it has. A `CardRef`
+/// borrows them instead, from a buffer that holds the json, such as a bulk
+/// data file read into memory or memory mapped. Only strings with escape
+/// sequences in them, like `\"`, are copied. Urls are kept as text, and the
+/// fields that are objects, like [`card_faces`](Card::card_faces) and
+/// [`prices`](Card::prices), are kept as raw json. All of them are parsed
+/// when the card is converted into a [`Card`] with
+/// [`into_owned`](CardRef::into_owned).
+///
+/// The fields have the same names and meanings as the fields of [`Card`].
+///
+/// # Examples
+/// ```rust,no_run
+/// use scryfall::bulk;
+/// use scryfall::card::CardRef;
+///
+/// # fn main() -> scryfall::Result<()> {
+/// let json = std::fs::read("all-cards.json")?;
+/// let mut creatures = 0;
+/// bulk::for_each_card_ref(&json, |card: CardRef| {
+///     if card.type_line.as_deref().is_some_and(|t| t.contains("Creature")) {
+///         creatures += 1;
+///     }
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub struct CardRef<'a> {
+    // region Core Card Fields
+    // =======================
+    pub arena_id: Option<usize>,
+    pub id: Uuid,
+    pub lang: Languages,
+    pub mtgo_id: Option<usize>,
+    pub mtgo_foil_id: Option<usize>,
+    pub multiverse_ids: Option<Vec<usize>>,
+    pub tcgplayer_id: Option<usize>,
+    pub tcgplayer_etched_id: Option<usize>,
+    pub cardmarket_id: Option<usize>,
+    pub oracle_id: Option<Uuid>,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub resource_id: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub prints_search_uri: Cow<'a, str>,
+    #[serde(borrow)]
+    pub rulings_uri: Cow<'a, str>,
+    #[serde(borrow)]
+    pub scryfall_uri: Cow<'a, str>,
+    #[serde(borrow)]
+    pub uri: Cow<'a, str>,
+    // ==========================
+    // endregion Core Card Fields
+    //
+    // region Gameplay Fields
+    // ======================
+    #[serde(borrow)]
+    pub all_parts: Option<&'a RawValue>,
+    #[serde(borrow)]
+    pub card_faces: Option<&'a RawValue>,
+    pub cmc: Option<f32>,
+    pub color_identity: Vec<Color>,
+    pub color_indicator: Option<Vec<Color>>,
+    pub colors: Option<Vec<Color>>,
+    pub edhrec_rank: Option<usize>,
+    pub game_changer: Option<bool>,
+    pub foil: bool,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub hand_modifier: Option<Cow<'a, str>>,
+    #[serde(borrow, deserialize_with = "borrow_vec")]
+    pub keywords: Vec<Cow<'a, str>>,
+    pub layout: Layout,
+    pub legalities: CardLegality,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub life_modifier: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub loyalty: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub mana_cost: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    pub nonfoil: bool,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub oracle_text: Option<Cow<'a, str>>,
+    pub oversized: bool,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub power: Option<Cow<'a, str>>,
+    pub produced_mana: Option<Vec<ProducedMana>>,
+    pub reserved: bool,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub toughness: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub type_line: Option<Cow<'a, str>>,
+    pub penny_rank: Option<u64>,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub defense: Option<Cow<'a, str>>,
+    // =========================
+    // endregion Gameplay Fields
+    //
+    // region Print Fields
+    // ===================
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub artist: Option<Cow<'a, str>>,
+    pub artist_ids: Option<Vec<Uuid>>,
+    pub booster: bool,
+    pub border_color: BorderColor,
+    pub card_back_id: Option<Uuid>,
+    #[serde(borrow)]
+    pub collector_number: Cow<'a, str>,
+    #[serde(default)]
+    pub content_warning: bool,
+    pub digital: bool,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub flavor_name: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub flavor_text: Option<Cow<'a, str>>,
+    #[serde(default)]
+    pub frame_effects: Vec<FrameEffect>,
+    pub frame: Frame,
+    pub full_art: bool,
+    pub games: Vec<Game>,
+    pub highres_image: bool,
+    pub illustration_id: Option<Uuid>,
+    pub image_status: ImageStatus,
+    #[serde(default, borrow)]
+    pub image_uris: Option<&'a RawValue>,
+    #[serde(default, borrow)]
+    pub prices: Option<&'a RawValue>,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub printed_name: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub printed_text: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub printed_type_line: Option<Cow<'a, str>>,
+    pub promo: bool,
+    #[serde(borrow)]
+    pub purchase_uris: Option<&'a RawValue>,
+    pub rarity: Rarity,
+    #[serde(borrow)]
+    pub related_uris: Option<&'a RawValue>,
+    pub released_at: NaiveDate,
+    pub reprint: bool,
+    #[serde(borrow)]
+    pub scryfall_set_uri: Cow<'a, str>,
+    #[serde(borrow)]
+    pub set_name: Cow<'a, str>,
+    #[serde(borrow)]
+    pub set_search_uri: Cow<'a, str>,
+    pub set_type: SetType,
+    #[serde(borrow)]
+    pub set_uri: Cow<'a, str>,
+    pub set: SetCode,
+    pub set_id: Uuid,
+    pub story_spotlight: bool,
+    pub textless: bool,
+    pub variation: bool,
+    pub variation_of: Option<Uuid>,
+    #[serde(default, borrow, deserialize_with = "borrow_option")]
+    pub watermark: Option<Cow<'a, str>>,
+    #[serde(default, borrow)]
+    pub preview: Option<&'a RawValue>,
+    pub finishes: Vec<Finishes>,
+    #[serde(default)]
+    pub security_stamp: Option<SecurityStamp>,
+    #[serde(default)]
+    pub promo_types: Vec<PromoType>,
+    pub attraction_lights: Option<Vec<u8>>,
+    // ======================
+    // endregion Print Fields
+    #[serde(borrow)]
+    pub image_updated_at: Cow<'a, str>,
+
+    #[cfg(test)]
+    #[serde(rename = "object")]
+    _object: Cow<'a, str>,
+}
+
+/// Text that borrows from the json it's deserialized from when it can.
+/// `#[serde(borrow)]` only does this for a `Cow` field, not for one inside an
+/// `Option` or a `Vec`.
+struct BorrowedText<'a>(Cow<'a, str>);
+
+impl<'de> Deserialize<'de> for BorrowedText<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TextVisitor;
+
+        impl<'de> Visitor<'de> for TextVisitor {
+            type Value = BorrowedText<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+                Ok(BorrowedText(Cow::Borrowed(v)))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(BorrowedText(Cow::Owned(v.to_string())))
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(BorrowedText(Cow::Owned(v)))
+            }
+        }
+
+        deserializer.deserialize_str(TextVisitor)
+    }
+}
+
+fn borrow_option<'de, D>(deserializer: D) -> Result<Option<Cow<'de, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<BorrowedText>::deserialize(deserializer)?.map(|text| text.0))
+}
+
+fn borrow_vec<'de, D>(deserializer: D) -> Result<Vec<Cow<'de, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Vec::<BorrowedText>::deserialize(deserializer)?
+        .into_iter()
+        .map(|text| text.0)
+        .collect())
+}
+
+/// Parses an object kept as raw json.
+fn parse<'a, T: Deserialize<'a>>(raw: Option<&'a RawValue>) -> crate::Result<Option<T>> {
+    Ok(raw.map(|raw| serde_json::from_str(raw.get())).transpose()?)
+}
+
+fn owned(text: Option<Cow<str>>) -> Option<String> {
+    text.map(Cow::into_owned)
+}
+
+impl<'a> CardRef<'a> {
+    /// Converts this card into an owned [`Card`], parsing its urls and the
+    /// fields kept as raw json.
+    ///
+    /// A card ref doesn't keep the top level fields it doesn't know about, so
+    /// with the `extra_fields` feature the card's `extra` fields are empty.
+    pub fn into_owned(self) -> crate::Result<Card> {
+        Ok(Card {
+            arena_id: self.arena_id,
+            id: self.id,
+            lang: self.lang,
+            mtgo_id: self.mtgo_id,
+            mtgo_foil_id: self.mtgo_foil_id,
+            multiverse_ids: self.multiverse_ids,
+            tcgplayer_id: self.tcgplayer_id,
+            tcgplayer_etched_id: self.tcgplayer_etched_id,
+            cardmarket_id: self.cardmarket_id,
+            oracle_id: self.oracle_id,
+            resource_id: owned(self.resource_id),
+            prints_search_uri: Uri::try_from(&*self.prints_search_uri)?,
+            rulings_uri: Uri::try_from(&*self.rulings_uri)?,
+            scryfall_uri: Url::parse(&self.scryfall_uri)?,
+            uri: Uri::try_from(&*self.uri)?,
+            all_parts: parse(self.all_parts)?,
+            card_faces: parse(self.card_faces)?,
+            cmc: self.cmc,
+            color_identity: self.color_identity,
+            color_indicator: self.color_indicator,
+            colors: self.colors,
+            edhrec_rank: self.edhrec_rank,
+            game_changer: self.game_changer,
+            foil: self.foil,
+            hand_modifier: owned(self.hand_modifier),
+            keywords: self.keywords.into_iter().map(Cow::into_owned).collect(),
+            layout: self.layout,
+            legalities: self.legalities,
+            life_modifier: owned(self.life_modifier),
+            loyalty: owned(self.loyalty),
+            mana_cost: owned(self.mana_cost),
+            name: self.name.into_owned(),
+            nonfoil: self.nonfoil,
+            oracle_text: owned(self.oracle_text),
+            oversized: self.oversized,
+            power: owned(self.power),
+            produced_mana: self.produced_mana,
+            reserved: self.reserved,
+            toughness: owned(self.toughness),
+            type_line: owned(self.type_line),
+            penny_rank: self.penny_rank,
+            defense: owned(self.defense),
+            artist: owned(self.artist),
+            artist_ids: self.artist_ids,
+            booster: self.booster,
+            border_color: self.border_color,
+            card_back_id: self.card_back_id,
+            collector_number: CollectorNumber::from(self.collector_number.into_owned()),
+            content_warning: self.content_warning,
+            digital: self.digital,
+            flavor_name: owned(self.flavor_name),
+            flavor_text: owned(self.flavor_text),
+            frame_effects: self.frame_effects,
+            frame: self.frame,
+            full_art: self.full_art,
+            games: self.games,
+            highres_image: self.highres_image,
+            illustration_id: self.illustration_id,
+            image_status: self.image_status,
+            image_uris: parse(self.image_uris)?,
+            prices: parse(self.prices)?.unwrap_or_default(),
+            printed_name: owned(self.printed_name),
+            printed_text: owned(self.printed_text),
+            printed_type_line: owned(self.printed_type_line),
+            promo: self.promo,
+            purchase_uris: parse(self.purchase_uris)?,
+            rarity: self.rarity,
+            related_uris: parse(self.related_uris)?,
+            released_at: self.released_at,
+            reprint: self.reprint,
+            scryfall_set_uri: self.scryfall_set_uri.into_owned(),
+            set_name: self.set_name.into_owned(),
+            set_search_uri: Uri::try_from(&*self.set_search_uri)?,
+            set_type: self.set_type,
+            set_uri: Uri::try_from(&*self.set_uri)?,
+            set: self.set,
+            set_id: self.set_id,
+            story_spotlight: self.story_spotlight,
+            textless: self.textless,
+            variation: self.variation,
+            variation_of: self.variation_of,
+            watermark: owned(self.watermark),
+            preview: parse(self.preview)?.unwrap_or_default(),
+            finishes: self.finishes,
+            security_stamp: self.security_stamp,
+            promo_types: self.promo_types,
+            attraction_lights: self.attraction_lights,
+            image_updated_at: self.image_updated_at.into_owned(),
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+            #[cfg(test)]
+            _object: self._object.into_owned(),
+        })
+    }
+}
+
+impl<'a> TryFrom<CardRef<'a>> for Card {
+    type Error = crate::Error;
+
+    fn try_from(card: CardRef<'a>) -> Result<Self, Self::Error> {
+        card.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::util::test_card::{card, card_json};
+
+    #[test]
+    fn borrows_text_from_the_json() {
+        let json = card_json(json!({ "keywords": ["Haste", "Storm"] })).to_string();
+        let card_ref: CardRef = serde_json::from_str(&json).unwrap();
+        assert!(matches!(card_ref.name, Cow::Borrowed("Lightning Bolt")));
+        assert!(matches!(card_ref.uri, Cow::Borrowed(_)));
+        assert!(matches!(
+            card_ref.oracle_text,
+            Some(Cow::Borrowed(
+                "Lightning Bolt deals 3 damage to any target."
+            ))
+        ));
+        assert!(matches!(
+            card_ref.keywords[..],
+            [Cow::Borrowed("Haste"), Cow::Borrowed("Storm")]
+        ));
+        assert_eq!(card_ref.resource_id, None);
+        assert!(card_ref.prices.is_some());
+    }
+
+    #[test]
+    fn copies_escaped_text() {
+        let json = card_json(json!({
+            "oracle_text": "Lightning Bolt deals 3 damage to \"any\" target.",
+            "keywords": ["\"Haste\""],
+        }))
+        .to_string();
+        let card_ref: CardRef = serde_json::from_str(&json).unwrap();
+        assert!(matches!(card_ref.name, Cow::Borrowed(_)));
+        assert!(matches!(
+            card_ref.oracle_text.as_ref(),
+            Some(Cow::Owned(text)) if text == "Lightning Bolt deals 3 damage to \"any\" target."
+        ));
+        assert!(matches!(&card_ref.keywords[..], [Cow::Owned(keyword)] if keyword == "\"Haste\""));
+    }
+
+    #[test]
+    fn converts_into_an_owned_card() {
+        let faces = json!([
+            {
+                "object": "card_face",
+                "name": "Fire",
+                "mana_cost": "{1}{R}",
+                "type_line": "Instant",
+            },
+            {
+                "object": "card_face",
+                "name": "Ice",
+                "mana_cost": "{1}{U}",
+                "type_line": "Instant",
+            },
+        ]);
+        let overrides = json!({ "name": "Fire // Ice", "card_faces": faces });
+        let json = card_json(overrides.clone()).to_string();
+        let card_ref: CardRef = serde_json::from_str(&json).unwrap();
+        let owned = card_ref.into_owned().unwrap();
+        assert_eq!(owned, card(overrides));
+
+        let broken = card_json(json!({ "uri": "not a url" })).to_string();
+        let card_ref: CardRef = serde_json::from_str(&broken).unwrap();
+        assert!(Card::try_from(card_ref).is_err());
+    }
+}
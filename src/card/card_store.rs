@@ -0,0 +1,1103 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use futures::{Stream, StreamExt};
+use url::Url;
+use uuid::Uuid;
+
+use super::purchase_uris::PurchaseUris;
+use super::related_uris::RelatedUris;
+use crate::card::{
+    BorderColor, Card, CardFace, CardLegality, CollectorNumber, Color, Finishes, Frame,
+    FrameEffect, Game, ImageStatus, ImageUris, Languages, Layout, Preview, Price, ProducedMana,
+    PromoType, Rarity, RelatedCard, SecurityStamp,
+};
+#[cfg(feature = "extra_fields")]
+use crate::extra_fields::ExtraFields;
+use crate::set::{SetCode, SetType};
+use crate::uri::Uri;
+use crate::util::{CARDS_URL, SETS_URL};
+
+/// A compact, in memory collection of cards, meant for holding entire bulk
+/// data files like [`all_cards`](crate::bulk::all_cards).
+///
+/// A `Vec<Card>` of every printing in every language takes several gigabytes,
+/// because each card owns its own copy of every string. A `CardStore` keeps
+/// them much smaller:
+///
+/// - Strings that repeat across printings, like set names, artists, flavor
+///   text, watermarks and prices, are stored once and shared.
+/// - The Oracle data of a card, like its name, rules text and legalities, is
+///   stored once for all the printings that share it.
+/// - Urls that Scryfall builds from the ids of the card, like its
+///   [`uri`](Card::uri) and its images, are rebuilt from those ids.
+///
+/// Nothing is lost: every card comes back out of the store exactly as it went
+/// in. The cards are rebuilt on demand, by [`get`](CardStore::get),
+/// [`get_by_id`](CardStore::get_by_id) and [`iter`](CardStore::iter), so each
+/// one is allocated again while it's in use.
+///
+/// # Examples
+/// ```rust,no_run
+/// use scryfall::bulk;
+/// use scryfall::card::{CardStore, Languages};
+///
+/// # tokio_test::block_on(async {
+/// let store = CardStore::from_stream(bulk::all_cards().await.unwrap())
+///     .await
+///     .unwrap();
+/// let german = store.iter().filter(|card| card.lang == Languages::German).count();
+/// println!("{} of {} printings are in German", german, store.len());
+/// # })
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct CardStore {
+    strings: Interner,
+    oracles: Vec<Oracle>,
+    oracle_index: HashMap<Option<Uuid>, Vec<u32>>,
+    printings: Vec<Printing>,
+    ids: HashMap<Uuid, u32>,
+}
+
+impl CardStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collects the cards of a bulk data stream into a store, stopping at
+    /// the first error.
+    pub async fn from_stream(
+        cards: impl Stream<Item = crate::Result<Card>>,
+    ) -> crate::Result<Self> {
+        let mut store = CardStore::new();
+        let mut cards = std::pin::pin!(cards);
+        while let Some(card) = cards.next().await {
+            store.insert(card?);
+        }
+        Ok(store)
+    }
+
+    /// Adds a card to the store. A card with the same [`id`](Card::id) as one
+    /// already in the store replaces it.
+    ///
+    /// The replaced card's Oracle data and strings stay in the store, even if
+    /// no other card uses them anymore, since finding out would mean going
+    /// through every card. A store that replaces many cards, for example to
+    /// apply each day's bulk data, can be rebuilt from its own
+    /// [`iter`](CardStore::iter) to reclaim that memory.
+    pub fn insert(&mut self, card: Card) {
+        let Card {
+            arena_id,
+            id,
+            lang,
+            mtgo_id,
+            mtgo_foil_id,
+            multiverse_ids,
+            tcgplayer_id,
+            tcgplayer_etched_id,
+            cardmarket_id,
+            oracle_id,
+            resource_id,
+            prints_search_uri,
+            rulings_uri,
+            scryfall_uri,
+            uri,
+            all_parts,
+            card_faces,
+            cmc,
+            color_identity,
+            color_indicator,
+            colors,
+            edhrec_rank,
+            game_changer,
+            foil,
+            hand_modifier,
+            keywords,
+            layout,
+            legalities,
+            life_modifier,
+            loyalty,
+            mana_cost,
+            name,
+            nonfoil,
+            oracle_text,
+            oversized,
+            power,
+            produced_mana,
+            reserved,
+            toughness,
+            type_line,
+            penny_rank,
+            defense,
+            artist,
+            artist_ids,
+            booster,
+            border_color,
+            card_back_id,
+            collector_number,
+            content_warning,
+            digital,
+            flavor_name,
+            flavor_text,
+            frame_effects,
+            frame,
+            full_art,
+            games,
+            highres_image,
+            illustration_id,
+            image_status,
+            image_uris,
+            prices,
+            printed_name,
+            printed_text,
+            printed_type_line,
+            promo,
+            purchase_uris,
+            rarity,
+            related_uris,
+            released_at,
+            reprint,
+            scryfall_set_uri,
+            set_name,
+            set_search_uri,
+            set_type,
+            set_uri,
+            set,
+            set_id,
+            story_spotlight,
+            textless,
+            variation,
+            variation_of,
+            watermark,
+            preview,
+            finishes,
+            security_stamp,
+            promo_types,
+            attraction_lights,
+            image_updated_at,
+            #[cfg(feature = "extra_fields")]
+            mut extra,
+            #[cfg(test)]
+                _object: _,
+        } = card;
+
+        let oracle = self.oracle(Oracle {
+            oracle_id,
+            name,
+            mana_cost,
+            cmc,
+            type_line,
+            oracle_text,
+            power,
+            toughness,
+            loyalty,
+            defense,
+            colors,
+            color_identity,
+            color_indicator,
+            keywords,
+            layout,
+            legalities,
+            reserved,
+            edhrec_rank,
+            penny_rank,
+            game_changer,
+            produced_mana,
+            hand_modifier,
+            life_modifier,
+        });
+
+        let strings = &mut self.strings;
+        let keys = Keys {
+            id,
+            oracle_id,
+            set: set.get(),
+            set_id,
+            collector_number: collector_number.as_str(),
+        };
+        // Every card Scryfall sends has `"object": "card"`, which would leave
+        // every printing with extra fields.
+        #[cfg(feature = "extra_fields")]
+        let card_object = extra.get("object").and_then(|object| object.as_str()) == Some("card");
+        #[cfg(feature = "extra_fields")]
+        if card_object {
+            extra.remove("object");
+        }
+        #[cfg(feature = "extra_fields")]
+        let extra = Extra {
+            card: extra,
+            image_uris: image_uris
+                .as_ref()
+                .map(|uris| uris.extra.clone())
+                .unwrap_or_default(),
+            prices: prices.extra,
+            purchase_uris: purchase_uris
+                .as_ref()
+                .map(|uris| uris.extra.clone())
+                .unwrap_or_default(),
+            related_uris: related_uris
+                .as_ref()
+                .map(|uris| uris.extra.clone())
+                .unwrap_or_default(),
+        };
+        let mut text = |text: Option<String>| text.map(|text| strings.intern(&text));
+        let printing = Printing {
+            oracle,
+            arena_id,
+            id,
+            lang,
+            mtgo_id,
+            mtgo_foil_id,
+            multiverse_ids: multiverse_ids.map(Vec::into_boxed_slice),
+            tcgplayer_id,
+            tcgplayer_etched_id,
+            cardmarket_id,
+            resource_id: text(resource_id),
+            artist: text(artist),
+            flavor_name: text(flavor_name),
+            flavor_text: text(flavor_text),
+            printed_name: text(printed_name),
+            printed_text: text(printed_text),
+            printed_type_line: text(printed_type_line),
+            watermark: text(watermark),
+            prices: [
+                prices.usd,
+                prices.usd_foil,
+                prices.eur,
+                prices.eur_foil,
+                prices.tix,
+                prices.usd_etched,
+            ]
+            .map(&mut text),
+            prints_search_uri: strings.link(&keys.prints_search(), prints_search_uri.as_str()),
+            rulings_uri: strings.link(&keys.uri(), rulings_uri.as_str()),
+            scryfall_uri: strings.link(&keys.scryfall(), scryfall_uri.as_str()),
+            uri: strings.link(&keys.uri(), uri.as_str()),
+            all_parts: all_parts.map(Vec::into_boxed_slice),
+            card_faces: card_faces.map(Vec::into_boxed_slice),
+            foil,
+            nonfoil,
+            oversized,
+            artist_ids: artist_ids.map(Vec::into_boxed_slice),
+            booster,
+            border_color,
+            card_back_id,
+            collector_number: strings.intern(collector_number.as_str()),
+            content_warning,
+            digital,
+            frame_effects: frame_effects.into_boxed_slice(),
+            frame,
+            full_art,
+            games: games.into_boxed_slice(),
+            highres_image,
+            illustration_id,
+            image_status,
+            image_uris: image_uris.map(|uris| {
+                let urls = [
+                    uris.png,
+                    uris.border_crop,
+                    uris.art_crop,
+                    uris.large,
+                    uris.normal,
+                    uris.small,
+                ];
+                std::array::from_fn(|i| {
+                    urls[i]
+                        .as_ref()
+                        .map(|url| strings.link(&keys.image(IMAGE_SIZES[i]), url.as_str()))
+                })
+            }),
+            promo,
+            purchase_uris: purchase_uris.map(|uris| {
+                [uris.tcg_player, uris.card_market, uris.card_hoarder]
+                    .map(|url| strings.intern_url(url))
+            }),
+            rarity,
+            related_uris: related_uris.map(|uris| {
+                [
+                    uris.gatherer,
+                    uris.tcg_player_infinite_articles,
+                    uris.tcg_player_infinite_decks,
+                    uris.edhrec,
+                ]
+                .map(|url| strings.intern_url(url))
+            }),
+            released_at,
+            reprint,
+            scryfall_set_uri: strings.link(&keys.scryfall_set(), &scryfall_set_uri),
+            set_name: strings.intern(&set_name),
+            set_search_uri: strings.link(&keys.set_search(), set_search_uri.as_str()),
+            set_type,
+            set_uri: strings.link(&keys.set_uri(), set_uri.as_str()),
+            set,
+            set_id,
+            story_spotlight,
+            textless,
+            variation,
+            variation_of,
+            preview: (preview != Preview::default()).then(|| Box::new(preview)),
+            finishes: finishes.into_boxed_slice(),
+            security_stamp,
+            promo_types: promo_types.into_boxed_slice(),
+            attraction_lights: attraction_lights.map(Vec::into_boxed_slice),
+            image_updated_at: strings.intern(&image_updated_at),
+            #[cfg(feature = "extra_fields")]
+            card_object,
+            #[cfg(feature = "extra_fields")]
+            extra: (extra != Extra::default()).then(|| Box::new(extra)),
+        };
+
+        match self.ids.get(&id) {
+            Some(&index) => self.printings[index as usize] = printing,
+            None => {
+                self.ids.insert(id, to_index(self.printings.len()));
+                self.printings.push(printing);
+            },
+        }
+    }
+
+    /// The number of cards in the store.
+    pub fn len(&self) -> usize {
+        self.printings.len()
+    }
+
+    /// Returns true if the store has no cards.
+    pub fn is_empty(&self) -> bool {
+        self.printings.is_empty()
+    }
+
+    /// The card at `index`, in the order the cards were first inserted.
+    pub fn get(&self, index: usize) -> Option<Card> {
+        self.printings
+            .get(index)
+            .map(|printing| self.card(printing))
+    }
+
+    /// The card with the given Scryfall id.
+    pub fn get_by_id(&self, id: Uuid) -> Option<Card> {
+        self.ids
+            .get(&id)
+            .and_then(|&index| self.get(index as usize))
+    }
+
+    /// Iterates over the cards, in the order they were first inserted.
+    pub fn iter(&self) -> impl Iterator<Item = Card> + '_ {
+        self.printings.iter().map(|printing| self.card(printing))
+    }
+
+    /// The index of `oracle` in the store, adding it if no other card has the
+    /// same Oracle data yet.
+    fn oracle(&mut self, oracle: Oracle) -> u32 {
+        let candidates = self.oracle_index.entry(oracle.oracle_id).or_default();
+        if let Some(&index) = candidates
+            .iter()
+            .find(|&&index| self.oracles[index as usize] == oracle)
+        {
+            return index;
+        }
+        let index = to_index(self.oracles.len());
+        candidates.push(index);
+        self.oracles.push(oracle);
+        index
+    }
+
+    fn card(&self, printing: &Printing) -> Card {
+        let Oracle {
+            oracle_id,
+            name,
+            mana_cost,
+            cmc,
+            type_line,
+            oracle_text,
+            power,
+            toughness,
+            loyalty,
+            defense,
+            colors,
+            color_identity,
+            color_indicator,
+            keywords,
+            layout,
+            legalities,
+            reserved,
+            edhrec_rank,
+            penny_rank,
+            game_changer,
+            produced_mana,
+            hand_modifier,
+            life_modifier,
+        } = self.oracles[printing.oracle as usize].clone();
+        let Printing {
+            oracle: _,
+            arena_id,
+            id,
+            lang,
+            mtgo_id,
+            mtgo_foil_id,
+            multiverse_ids,
+            tcgplayer_id,
+            tcgplayer_etched_id,
+            cardmarket_id,
+            resource_id,
+            prints_search_uri,
+            rulings_uri,
+            scryfall_uri,
+            uri,
+            all_parts,
+            card_faces,
+            foil,
+            nonfoil,
+            oversized,
+            artist,
+            artist_ids,
+            booster,
+            border_color,
+            card_back_id,
+            collector_number,
+            content_warning,
+            digital,
+            flavor_name,
+            flavor_text,
+            frame_effects,
+            frame,
+            full_art,
+            games,
+            highres_image,
+            illustration_id,
+            image_status,
+            image_uris,
+            prices,
+            printed_name,
+            printed_text,
+            printed_type_line,
+            promo,
+            purchase_uris,
+            rarity,
+            related_uris,
+            released_at,
+            reprint,
+            scryfall_set_uri,
+            set_name,
+            set_search_uri,
+            set_type,
+            set_uri,
+            set,
+            set_id,
+            story_spotlight,
+            textless,
+            variation,
+            variation_of,
+            watermark,
+            preview,
+            finishes,
+            security_stamp,
+            promo_types,
+            attraction_lights,
+            image_updated_at,
+            #[cfg(feature = "extra_fields")]
+            card_object,
+            #[cfg(feature = "extra_fields")]
+            extra,
+        } = printing.clone();
+        #[cfg(feature = "extra_fields")]
+        let mut extra = extra.map(|extra| *extra).unwrap_or_default();
+        #[cfg(feature = "extra_fields")]
+        if card_object {
+            extra.card.insert("object".to_string(), "card".into());
+        }
+
+        let strings = &self.strings;
+        let collector_number = strings.get(collector_number);
+        let keys = Keys {
+            id,
+            oracle_id,
+            set: set.get(),
+            set_id,
+            collector_number,
+        };
+        let text = |sym: Option<Sym>| sym.map(|sym| strings.get(sym).to_string());
+        let url = |sym: Option<Sym>| sym.map(|sym| strings.url(sym));
+        let [usd, usd_foil, eur, eur_foil, tix, usd_etched] = prices.map(text);
+        Card {
+            arena_id,
+            id,
+            lang,
+            mtgo_id,
+            mtgo_foil_id,
+            multiverse_ids: multiverse_ids.map(Vec::from),
+            tcgplayer_id,
+            tcgplayer_etched_id,
+            cardmarket_id,
+            oracle_id,
+            resource_id: text(resource_id),
+            prints_search_uri: strings.resolve_uri(&keys.prints_search(), prints_search_uri),
+            rulings_uri: strings.resolve_uri(&keys.uri(), rulings_uri),
+            scryfall_uri: strings.resolve_url(&keys.scryfall(), scryfall_uri),
+            uri: strings.resolve_uri(&keys.uri(), uri),
+            all_parts: all_parts.map(Vec::from),
+            card_faces: card_faces.map(Vec::from),
+            cmc,
+            color_identity,
+            color_indicator,
+            colors,
+            edhrec_rank,
+            game_changer,
+            foil,
+            hand_modifier,
+            keywords,
+            layout,
+            legalities,
+            life_modifier,
+            loyalty,
+            mana_cost,
+            name,
+            nonfoil,
+            oracle_text,
+            oversized,
+            power,
+            produced_mana,
+            reserved,
+            toughness,
+            type_line,
+            penny_rank,
+            defense,
+            artist: text(artist),
+            artist_ids: artist_ids.map(Vec::from),
+            booster,
+            border_color,
+            card_back_id,
+            collector_number: CollectorNumber::from(collector_number),
+            content_warning,
+            digital,
+            flavor_name: text(flavor_name),
+            flavor_text: text(flavor_text),
+            frame_effects: frame_effects.into(),
+            frame,
+            full_art,
+            games: games.into(),
+            highres_image,
+            illustration_id,
+            image_status,
+            image_uris: image_uris.map(|links| {
+                let [png, border_crop, art_crop, large, normal, small] = std::array::from_fn(|i| {
+                    links[i].map(|link| strings.resolve_url(&keys.image(IMAGE_SIZES[i]), link))
+                });
+                ImageUris {
+                    png,
+                    border_crop,
+                    art_crop,
+                    large,
+                    normal,
+                    small,
+                    #[cfg(feature = "extra_fields")]
+                    extra: extra.image_uris,
+                }
+            }),
+            prices: Price {
+                usd,
+                usd_foil,
+                eur,
+                eur_foil,
+                tix,
+                usd_etched,
+                #[cfg(feature = "extra_fields")]
+                extra: extra.prices,
+            },
+            printed_name: text(printed_name),
+            printed_text: text(printed_text),
+            printed_type_line: text(printed_type_line),
+            promo,
+            purchase_uris: purchase_uris.map(|uris| {
+                let [tcg_player, card_market, card_hoarder] = uris.map(url);
+                PurchaseUris {
+                    tcg_player,
+                    card_market,
+                    card_hoarder,
+                    #[cfg(feature = "extra_fields")]
+                    extra: extra.purchase_uris,
+                }
+            }),
+            rarity,
+            related_uris: related_uris.map(|uris| {
+                let [gatherer, tcg_player_infinite_articles, tcg_player_infinite_decks, edhrec] =
+                    uris.map(url);
+                RelatedUris {
+                    gatherer,
+                    tcg_player_infinite_articles,
+                    tcg_player_infinite_decks,
+                    edhrec,
+                    #[cfg(feature = "extra_fields")]
+                    extra: extra.related_uris,
+                }
+            }),
+            released_at,
+            reprint,
+            scryfall_set_uri: strings.resolve(&keys.scryfall_set(), scryfall_set_uri),
+            set_name: strings.get(set_name).to_string(),
+            set_search_uri: strings.resolve_uri(&keys.set_search(), set_search_uri),
+            set_type,
+            set_uri: strings.resolve_uri(&keys.set_uri(), set_uri),
+            set,
+            set_id,
+            story_spotlight,
+            textless,
+            variation,
+            variation_of,
+            watermark: text(watermark),
+            preview: preview.map(|preview| *preview).unwrap_or_default(),
+            finishes: finishes.into(),
+            security_stamp,
+            promo_types: promo_types.into(),
+            attraction_lights: attraction_lights.map(Vec::from),
+            image_updated_at: strings.get(image_updated_at).to_string(),
+            #[cfg(feature = "extra_fields")]
+            extra: extra.card,
+            #[cfg(test)]
+            _object: "card".to_string(),
+        }
+    }
+}
+
+impl Extend<Card> for CardStore {
+    fn extend<I: IntoIterator<Item = Card>>(&mut self, cards: I) {
+        for card in cards {
+            self.insert(card);
+        }
+    }
+}
+
+impl FromIterator<Card> for CardStore {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        let mut store = CardStore::new();
+        store.extend(cards);
+        store
+    }
+}
+
+fn to_index(len: usize) -> u32 {
+    u32::try_from(len).expect("a card store holds at most u32::MAX items")
+}
+
+/// A string stored in an [`Interner`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct Sym(u32);
+
+/// The strings of a [`CardStore`], each stored once.
+#[derive(Clone, Default, Debug)]
+struct Interner {
+    strings: Vec<Arc<str>>,
+    syms: HashMap<Arc<str>, Sym>,
+}
+
+impl Interner {
+    fn intern(&mut self, s: &str) -> Sym {
+        if let Some(&sym) = self.syms.get(s) {
+            return sym;
+        }
+        let sym = Sym(to_index(self.strings.len()));
+        let s: Arc<str> = Arc::from(s);
+        self.strings.push(s.clone());
+        self.syms.insert(s, sym);
+        sym
+    }
+
+    fn get(&self, sym: Sym) -> &str {
+        &self.strings[sym.0 as usize]
+    }
+
+    fn intern_url(&mut self, url: Option<Url>) -> Option<Sym> {
+        url.map(|url| self.intern(url.as_str()))
+    }
+
+    fn url(&self, sym: Sym) -> Url {
+        Url::parse(self.get(sym)).expect("only valid urls are interned as urls")
+    }
+
+    /// Stores `url`, leaving out `prefix` if it starts with it.
+    fn link(&mut self, prefix: &str, url: &str) -> Link {
+        match url.strip_prefix(prefix) {
+            Some(rest) if !prefix.is_empty() => Link::Derived(self.intern(rest)),
+            _ => Link::Verbatim(self.intern(url)),
+        }
+    }
+
+    fn resolve(&self, prefix: &str, link: Link) -> String {
+        match link {
+            Link::Derived(rest) => format!("{}{}", prefix, self.get(rest)),
+            Link::Verbatim(url) => self.get(url).to_string(),
+        }
+    }
+
+    fn resolve_url(&self, prefix: &str, link: Link) -> Url {
+        Url::parse(&self.resolve(prefix, link)).expect("only valid urls are stored as links")
+    }
+
+    fn resolve_uri<T>(&self, prefix: &str, link: Link) -> Uri<T> {
+        Uri::from(self.resolve_url(prefix, link))
+    }
+}
+
+/// A url that may be rebuilt from the [`Keys`] of its card.
+#[derive(Copy, Clone, Debug)]
+enum Link {
+    /// The url is the prefix built from the keys, followed by this string.
+    Derived(Sym),
+    /// The url doesn't follow the usual pattern, so it's stored whole.
+    Verbatim(Sym),
+}
+
+/// The ids that Scryfall builds the urls of a card from.
+struct Keys<'a> {
+    id: Uuid,
+    oracle_id: Option<Uuid>,
+    set: &'a str,
+    set_id: Uuid,
+    collector_number: &'a str,
+}
+
+impl Keys<'_> {
+    fn uri(&self) -> String {
+        format!("{}{}", CARDS_URL.as_str(), self.id)
+    }
+
+    fn prints_search(&self) -> String {
+        match self.oracle_id {
+            Some(oracle_id) => format!(
+                "{}search?order=released&q=oracleid%3A{}",
+                CARDS_URL.as_str(),
+                oracle_id
+            ),
+            None => String::new(),
+        }
+    }
+
+    fn scryfall(&self) -> String {
+        format!(
+            "https://scryfall.com/card/{}/{}/",
+            self.set, self.collector_number
+        )
+    }
+
+    fn set_uri(&self) -> String {
+        format!("{}{}", SETS_URL.as_str(), self.set_id)
+    }
+
+    fn set_search(&self) -> String {
+        format!("{}search?order=set&q=e%3A{}", CARDS_URL.as_str(), self.set)
+    }
+
+    fn scryfall_set(&self) -> String {
+        format!("https://scryfall.com/sets/{}", self.set)
+    }
+
+    fn image(&self, size: &str) -> String {
+        let id = self.id.to_string();
+        format!(
+            "https://cards.scryfall.io/{}/front/{}/{}/{}",
+            size,
+            &id[..1],
+            &id[1..2],
+            id
+        )
+    }
+}
+
+/// The image sizes, in the order they're stored in.
+const IMAGE_SIZES: [&str; 6] = ["png", "border_crop", "art_crop", "large", "normal", "small"];
+
+/// The fields of a card that are usually the same for all of its printings.
+#[derive(Clone, PartialEq, Debug)]
+struct Oracle {
+    oracle_id: Option<Uuid>,
+    name: String,
+    mana_cost: Option<String>,
+    cmc: Option<f32>,
+    type_line: Option<String>,
+    oracle_text: Option<String>,
+    power: Option<String>,
+    toughness: Option<String>,
+    loyalty: Option<String>,
+    defense: Option<String>,
+    colors: Option<Vec<Color>>,
+    color_identity: Vec<Color>,
+    color_indicator: Option<Vec<Color>>,
+    keywords: Vec<String>,
+    layout: Layout,
+    legalities: CardLegality,
+    reserved: bool,
+    edhrec_rank: Option<usize>,
+    penny_rank: Option<u64>,
+    game_changer: Option<bool>,
+    produced_mana: Option<Vec<ProducedMana>>,
+    hand_modifier: Option<String>,
+    life_modifier: Option<String>,
+}
+
+/// The fields of a card that are particular to one printing, with their
+/// strings interned.
+#[derive(Clone, Debug)]
+struct Printing {
+    oracle: u32,
+    arena_id: Option<usize>,
+    id: Uuid,
+    lang: Languages,
+    mtgo_id: Option<usize>,
+    mtgo_foil_id: Option<usize>,
+    multiverse_ids: Option<Box<[usize]>>,
+    tcgplayer_id: Option<usize>,
+    tcgplayer_etched_id: Option<usize>,
+    cardmarket_id: Option<usize>,
+    resource_id: Option<Sym>,
+    prints_search_uri: Link,
+    rulings_uri: Link,
+    scryfall_uri: Link,
+    uri: Link,
+    all_parts: Option<Box<[RelatedCard]>>,
+    card_faces: Option<Box<[CardFace]>>,
+    foil: bool,
+    nonfoil: bool,
+    oversized: bool,
+    artist: Option<Sym>,
+    artist_ids: Option<Box<[Uuid]>>,
+    booster: bool,
+    border_color: BorderColor,
+    card_back_id: Option<Uuid>,
+    collector_number: Sym,
+    content_warning: bool,
+    digital: bool,
+    flavor_name: Option<Sym>,
+    flavor_text: Option<Sym>,
+    frame_effects: Box<[FrameEffect]>,
+    frame: Frame,
+    full_art: bool,
+    games: Box<[Game]>,
+    highres_image: bool,
+    illustration_id: Option<Uuid>,
+    image_status: ImageStatus,
+    image_uris: Option<[Option<Link>; 6]>,
+    prices: [Option<Sym>; 6],
+    printed_name: Option<Sym>,
+    printed_text: Option<Sym>,
+    printed_type_line: Option<Sym>,
+    promo: bool,
+    purchase_uris: Option<[Option<Sym>; 3]>,
+    rarity: Rarity,
+    related_uris: Option<[Option<Sym>; 4]>,
+    released_at: NaiveDate,
+    reprint: bool,
+    scryfall_set_uri: Link,
+    set_name: Sym,
+    set_search_uri: Link,
+    set_type: SetType,
+    set_uri: Link,
+    set: SetCode,
+    set_id: Uuid,
+    story_spotlight: bool,
+    textless: bool,
+    variation: bool,
+    variation_of: Option<Uuid>,
+    watermark: Option<Sym>,
+    preview: Option<Box<Preview>>,
+    finishes: Box<[Finishes]>,
+    security_stamp: Option<SecurityStamp>,
+    promo_types: Box<[PromoType]>,
+    attraction_lights: Option<Box<[u8]>>,
+    image_updated_at: Sym,
+    /// Whether the card's extra fields had `"object": "card"`, which is kept
+    /// out of `extra`.
+    #[cfg(feature = "extra_fields")]
+    card_object: bool,
+    #[cfg(feature = "extra_fields")]
+    extra: Option<Box<Extra>>,
+}
+
+/// The fields of a card and its nested objects that this crate doesn't know
+/// about, kept apart since few cards have any.
+#[cfg(feature = "extra_fields")]
+#[derive(Default, Clone, PartialEq, Debug)]
+struct Extra {
+    card: ExtraFields,
+    image_uris: ExtraFields,
+    prices: ExtraFields,
+    purchase_uris: ExtraFields,
+    related_uris: ExtraFields,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::util::test_alloc;
+    use crate::util::test_card::{card, card_json};
+
+    const LANGS: [&str; 6] = ["en", "de", "fr", "ja", "es", "it"];
+    const ARTISTS: [&str; 2] = ["Christopher Moeller", "Christopher Rush"];
+    const SETS: [(&str, &str); 5] = [
+        ("m10", "Magic 2010"),
+        ("m11", "Magic 2011"),
+        ("2xm", "Double Masters"),
+        ("a25", "Masters 25"),
+        ("clb", "Commander Legends: Battle for Baldur's Gate"),
+    ];
+
+    /// Every language of every printing of a few cards, like in the
+    /// `all_cards` bulk data file.
+    fn printings() -> Vec<Card> {
+        let mut cards = Vec::new();
+        for oracle in 0..8u128 {
+            let name = format!("Lightning Bolt {}", oracle);
+            let oracle_id = Uuid::from_u128(oracle + 1);
+            for (set_index, (set, set_name)) in SETS.into_iter().enumerate() {
+                let set_id = Uuid::from_u128(1000 + set_index as u128);
+                let number = 100 + oracle;
+                for (lang_index, lang) in LANGS.into_iter().enumerate() {
+                    let id = Uuid::from_u128(
+                        (oracle << 16) | ((set_index as u128) << 8) | lang_index as u128,
+                    );
+                    let image = |size: &str, ext: &str| {
+                        let id = id.to_string();
+                        format!(
+                            "https://cards.scryfall.io/{}/front/{}/{}/{}.{}?1562404626",
+                            size,
+                            &id[..1],
+                            &id[1..2],
+                            id,
+                            ext
+                        )
+                    };
+                    cards.push(card(json!({
+                        "id": id,
+                        "oracle_id": oracle_id,
+                        "lang": lang,
+                        "name": name,
+                        "oracle_text": format!("{} deals 3 damage to any target.", name),
+                        "printed_name": (lang != "en").then(|| format!("{} ({})", name, lang)),
+                        "uri": format!("https://api.scryfall.com/cards/{}", id),
+                        "rulings_uri": format!("https://api.scryfall.com/cards/{}/rulings", id),
+                        "scryfall_uri": format!(
+                            "https://scryfall.com/card/{}/{}/{}/lightning-bolt?utm_source=api",
+                            set, number, lang
+                        ),
+                        "prints_search_uri": format!(
+                            "https://api.scryfall.com/cards/search?order=released&q=oracleid%3A{}&unique=prints",
+                            oracle_id
+                        ),
+                        "set": set,
+                        "set_name": set_name,
+                        "set_id": set_id,
+                        "set_uri": format!("https://api.scryfall.com/sets/{}", set_id),
+                        "set_search_uri": format!(
+                            "https://api.scryfall.com/cards/search?order=set&q=e%3A{}&unique=prints",
+                            set
+                        ),
+                        "scryfall_set_uri": format!("https://scryfall.com/sets/{}?utm_source=api", set),
+                        "collector_number": number.to_string(),
+                        "artist": ARTISTS[set_index % 2],
+                        "flavor_text": "The sparkmage shrieked, calling on the rage of the storms of his youth.",
+                        "image_uris": {
+                            "png": image("png", "png"),
+                            "border_crop": image("border_crop", "jpg"),
+                            "art_crop": image("art_crop", "jpg"),
+                            "large": image("large", "jpg"),
+                            "normal": image("normal", "jpg"),
+                            "small": image("small", "jpg"),
+                        },
+                        "purchase_uris": {
+                            "tcgplayer": format!("https://www.tcgplayer.com/product/{}", id),
+                            "cardmarket": "https://www.cardmarket.com/en/Magic/Products/Search?searchString=Lightning+Bolt",
+                            "cardhoarder": null,
+                        },
+                    })));
+                }
+            }
+        }
+        cards
+    }
+
+    #[test]
+    fn gives_back_the_cards_it_was_given() {
+        let cards = printings();
+        let store: CardStore = cards.iter().cloned().collect();
+        assert_eq!(store.len(), cards.len());
+        assert_eq!(store.oracles.len(), 8);
+        assert!(store.iter().eq(cards.iter().cloned()));
+        assert_eq!(store.get_by_id(cards[42].id).as_ref(), Some(&cards[42]));
+        assert_eq!(store.get(cards.len()), None);
+    }
+
+    #[test]
+    fn keeps_unusual_values() {
+        // Languages this crate doesn't know about only deserialize with
+        // `unknown_variants`.
+        let lang = if cfg!(feature = "unknown_variants") {
+            "xx"
+        } else {
+            "en"
+        };
+        let odd = card_json(json!({
+            "lang": lang,
+            "uri": "https://example.com/card",
+            "prints_search_uri": "https://example.com/prints",
+            "preview": {
+                "source": "Wizards of the Coast",
+                "source_uri": "https://magic.wizards.com/",
+                "previewed_at": "2009-06-01",
+            },
+            "oracle_id": null,
+        }));
+        let odd: Card = serde_json::from_value(odd).unwrap();
+        let mut store = CardStore::new();
+        store.insert(card(json!({})));
+        store.insert(odd.clone());
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.get(0), Some(odd));
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "unknown_variants", feature = "unknown_variants_slim")))]
+    fn unknown_languages_need_unknown_variants() {
+        let json = card_json(json!({ "lang": "xx" }));
+        assert!(serde_json::from_value::<Card>(json).is_err());
+    }
+
+    #[test]
+    fn rebuilding_drops_the_data_of_replaced_cards() {
+        let mut store = CardStore::new();
+        store.insert(card(json!({})));
+        store.insert(card(
+            json!({ "oracle_text": "Deal 3 damage to any target." }),
+        ));
+        let rebuilt = store.iter().collect::<CardStore>();
+        assert!(rebuilt.iter().eq(store.iter()));
+        assert_eq!(rebuilt.oracles.len(), 1);
+    }
+
+    #[test]
+    fn is_smaller_than_a_vec_of_cards() {
+        let json = serde_json::to_string(&printings()).unwrap();
+        let (cards, cards_size) =
+            test_alloc::measure(|| serde_json::from_str::<Vec<Card>>(&json).unwrap());
+        let (store, store_size) =
+            test_alloc::measure(|| cards.iter().cloned().collect::<CardStore>());
+        assert_eq!(store.len(), cards.len());
+        assert!(
+            store_size * 4 < cards_size,
+            "the store takes {} bytes, and the cards {} bytes",
+            store_size,
+            cards_size
+        );
+    }
+
+    #[cfg(feature = "extra_fields")]
+    #[test]
+    fn does_not_keep_extra_fields_for_the_kind_of_object() {
+        let mut bolt = card(json!({}));
+        bolt.extra.insert("object".to_string(), json!("card"));
+        let store = CardStore::from_iter([bolt.clone()]);
+        assert!(store.printings[0].extra.is_none());
+        assert_eq!(store.get(0), Some(bolt));
+    }
+}
@@ -0,0 +1,257 @@
+use std::convert::TryFrom;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::card::{Card, CollectorNumber};
+use crate::set::SetCode;
+
+/// Identifies a card to look up with [`Card::collection`].
+///
+/// For more information, refer to the [official docs](https://scryfall.com/docs/api/cards/collection#card-identifiers).
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(into = "RawIdentifier", try_from = "RawIdentifier")]
+pub enum CardIdentifier {
+    /// A card's Scryfall id.
+    Id(Uuid),
+    /// A card's Magic Online id.
+    MtgoId(usize),
+    /// A card's multiverse id.
+    MultiverseId(usize),
+    /// The oracle id of a card. The most recent printing is returned.
+    OracleId(Uuid),
+    /// The illustration id of a card. The preferred printing is returned.
+    IllustrationId(Uuid),
+    /// The exact name of a card. The most recent printing is returned.
+    Name(String),
+    /// The exact name of a card, printed in a set.
+    NameAndSet {
+        /// The name of the card.
+        name: String,
+        /// The set it was printed in.
+        set: SetCode,
+    },
+    /// A card's collector number within a set.
+    CollectorNumberAndSet {
+        /// The collector number of the card.
+        collector_number: CollectorNumber,
+        /// The set it was printed in.
+        set: SetCode,
+    },
+}
+
+/// The json representation of a [`CardIdentifier`], with only the fields of
+/// one of its variants set.
+#[derive(Serialize, Deserialize, Default)]
+struct RawIdentifier {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mtgo_id: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    multiverse_id: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    oracle_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    illustration_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collector_number: Option<CollectorNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    set: Option<SetCode>,
+}
+
+impl From<CardIdentifier> for RawIdentifier {
+    fn from(identifier: CardIdentifier) -> Self {
+        let mut raw = RawIdentifier::default();
+        match identifier {
+            CardIdentifier::Id(id) => raw.id = Some(id),
+            CardIdentifier::MtgoId(id) => raw.mtgo_id = Some(id),
+            CardIdentifier::MultiverseId(id) => raw.multiverse_id = Some(id),
+            CardIdentifier::OracleId(id) => raw.oracle_id = Some(id),
+            CardIdentifier::IllustrationId(id) => raw.illustration_id = Some(id),
+            CardIdentifier::Name(name) => raw.name = Some(name),
+            CardIdentifier::NameAndSet { name, set } => {
+                raw.name = Some(name);
+                raw.set = Some(set);
+            },
+            CardIdentifier::CollectorNumberAndSet {
+                collector_number,
+                set,
+            } => {
+                raw.collector_number = Some(collector_number);
+                raw.set = Some(set);
+            },
+        }
+        raw
+    }
+}
+
+impl TryFrom<RawIdentifier> for CardIdentifier {
+    type Error = String;
+
+    fn try_from(raw: RawIdentifier) -> Result<Self, Self::Error> {
+        Ok(match raw {
+            RawIdentifier { id: Some(id), .. } => CardIdentifier::Id(id),
+            RawIdentifier {
+                mtgo_id: Some(id), ..
+            } => CardIdentifier::MtgoId(id),
+            RawIdentifier {
+                multiverse_id: Some(id),
+                ..
+            } => CardIdentifier::MultiverseId(id),
+            RawIdentifier {
+                oracle_id: Some(id),
+                ..
+            } => CardIdentifier::OracleId(id),
+            RawIdentifier {
+                illustration_id: Some(id),
+                ..
+            } => CardIdentifier::IllustrationId(id),
+            RawIdentifier {
+                name: Some(name),
+                set: Some(set),
+                ..
+            } => CardIdentifier::NameAndSet { name, set },
+            RawIdentifier {
+                name: Some(name), ..
+            } => CardIdentifier::Name(name),
+            RawIdentifier {
+                collector_number: Some(collector_number),
+                set: Some(set),
+                ..
+            } => CardIdentifier::CollectorNumberAndSet {
+                collector_number,
+                set,
+            },
+            _ => return Err("unrecognized card identifier".to_string()),
+        })
+    }
+}
+
+/// The result of looking up cards with [`Card::collection`].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CardCollection {
+    /// The cards that were found, in the order of the identifiers that matched
+    /// them.
+    pub data: Vec<Card>,
+
+    /// The identifiers that didn't match any card, in the order they were
+    /// given.
+    pub not_found: Vec<CardIdentifier>,
+}
+
+/// The response to a single request to the collection endpoint.
+#[derive(Deserialize)]
+pub(crate) struct CollectionPage {
+    pub(crate) data: Vec<Card>,
+    #[serde(default)]
+    pub(crate) not_found: Vec<CardIdentifier>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::client::{RateLimiter, ScryfallClient};
+    use crate::util::test_card::card_json;
+    use crate::util::test_server::TestServer;
+
+    fn set(code: &str) -> SetCode {
+        SetCode::try_from(code).unwrap()
+    }
+
+    #[test]
+    fn identifiers_serialize_to_their_fields() {
+        let cases = [
+            (CardIdentifier::MtgoId(54957), json!({ "mtgo_id": 54957 })),
+            (
+                CardIdentifier::Name("Lightning Bolt".to_string()),
+                json!({ "name": "Lightning Bolt" }),
+            ),
+            (
+                CardIdentifier::NameAndSet {
+                    name: "Lightning Bolt".to_string(),
+                    set: set("m10"),
+                },
+                json!({ "name": "Lightning Bolt", "set": "m10" }),
+            ),
+            (
+                CardIdentifier::CollectorNumberAndSet {
+                    collector_number: CollectorNumber::from(146),
+                    set: set("m10"),
+                },
+                json!({ "collector_number": "146", "set": "m10" }),
+            ),
+        ];
+        for (identifier, expected) in cases {
+            assert_eq!(serde_json::to_value(&identifier).unwrap(), expected);
+            assert_eq!(
+                serde_json::from_value::<CardIdentifier>(expected).unwrap(),
+                identifier
+            );
+        }
+        assert!(serde_json::from_value::<CardIdentifier>(json!({ "set": "m10" })).is_err());
+    }
+
+    #[tokio::test]
+    async fn looks_up_cards_in_chunks() {
+        let first_page = json!({
+            "object": "list",
+            "not_found": [{ "name": "Not A Card" }],
+            "data": [card_json(json!({})), card_json(json!({ "name": "Shock" }))],
+        })
+        .to_string();
+        let second_page = json!({
+            "object": "list",
+            "not_found": [],
+            "data": [card_json(json!({ "name": "Opt" }))],
+        })
+        .to_string();
+        let server = TestServer::start(vec![(200, first_page), (200, second_page)]).await;
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .build()
+            .unwrap();
+
+        let mut identifiers = vec![
+            CardIdentifier::Name("Lightning Bolt".to_string()),
+            CardIdentifier::Name("Not A Card".to_string()),
+            CardIdentifier::Name("Shock".to_string()),
+        ];
+        identifiers.extend((0..72).map(CardIdentifier::MtgoId));
+        identifiers.push(CardIdentifier::Name("Opt".to_string()));
+
+        let collection = client.scope(Card::collection(identifiers)).await.unwrap();
+        let names = collection.data.iter().map(|c| &c.name).collect::<Vec<_>>();
+        assert_eq!(names, ["Lightning Bolt", "Shock", "Opt"]);
+        assert_eq!(
+            collection.not_found,
+            [CardIdentifier::Name("Not A Card".to_string())]
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /cards/collection HTTP/1.1"));
+        let body = |request: &str| -> serde_json::Value {
+            serde_json::from_str(request.split("\r\n\r\n").nth(1).unwrap()).unwrap()
+        };
+        assert_eq!(
+            body(&requests[0])["identifiers"].as_array().unwrap().len(),
+            75
+        );
+        assert_eq!(
+            body(&requests[1]),
+            json!({ "identifiers": [{ "name": "Opt" }] })
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_collections_make_no_requests() {
+        let collection = Card::collection(Vec::new()).await.unwrap();
+        assert_eq!(collection, CardCollection::default());
+    }
+}
@@ -0,0 +1,231 @@
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// The star some sets use to mark alternate versions of a card, as in `★12`
+/// or `75★`.
+const STAR: char = '★';
+
+/// A card's collector number within its set.
+///
+/// Collector numbers are usually plain numbers, but may have a prefix or a
+/// suffix, like `S3`, `123a` or `2021-7`, or be marked with a star, like
+/// `★12`. The original text is always kept, so a collector number displays
+/// exactly as Scryfall wrote it.
+///
+/// Collector numbers are ordered naturally, which is the order cards appear in
+/// a set: the numeric parts are compared as numbers, so `9 < 10 < 10a < 11`.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::CollectorNumber;
+///
+/// let number: CollectorNumber = "★12a".parse().unwrap();
+/// assert_eq!(number.number(), Some(12));
+/// assert_eq!(number.suffix(), "a");
+/// assert!(number.is_star());
+/// assert_eq!(number.to_string(), "★12a");
+///
+/// assert!(CollectorNumber::from(9) < "10".parse().unwrap());
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(transparent)]
+pub struct CollectorNumber(String);
+
+impl CollectorNumber {
+    /// The collector number as Scryfall wrote it.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The first run of digits of the collector number, if it has any and
+    /// they fit in a `u32`.
+    pub fn number(&self) -> Option<u32> {
+        self.parts().1.parse().ok()
+    }
+
+    /// Whatever comes before the number, not counting a star. This is empty
+    /// for most cards.
+    pub fn prefix(&self) -> &str {
+        self.parts().0
+    }
+
+    /// Whatever comes after the number, not counting a star. This is empty for
+    /// most cards.
+    pub fn suffix(&self) -> &str {
+        self.parts().2
+    }
+
+    /// Returns true if the collector number is marked with a star.
+    pub fn is_star(&self) -> bool {
+        self.0.starts_with(STAR) || self.0.ends_with(STAR)
+    }
+
+    /// Splits the collector number, without its star, into the text before the
+    /// first run of digits, the digits and the text after them.
+    fn parts(&self) -> (&str, &str, &str) {
+        let unstarred = self.0.trim_start_matches(STAR).trim_end_matches(STAR);
+        let start = unstarred
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(unstarred.len());
+        let end = unstarred[start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(unstarred.len(), |len| start + len);
+        (
+            &unstarred[..start],
+            &unstarred[start..end],
+            &unstarred[end..],
+        )
+    }
+}
+
+/// Compares two strings, treating runs of digits as numbers.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let digits = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                        digits.push(c);
+                    }
+                    digits
+                };
+                let (x, y) = (digits(&mut a), digits(&mut b));
+                let (x, y) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+                let order = x.len().cmp(&y.len()).then_with(|| x.cmp(y));
+                if order != Ordering::Equal {
+                    return order;
+                }
+            },
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a.next();
+                b.next();
+            },
+        }
+    }
+}
+
+impl Ord for CollectorNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let unstarred = |n: &CollectorNumber| n.0.replace(STAR, "");
+        natural_cmp(&unstarred(self), &unstarred(other))
+            .then_with(|| self.is_star().cmp(&other.is_star()))
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl PartialOrd for CollectorNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for CollectorNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for CollectorNumber {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(CollectorNumber::from(s))
+    }
+}
+
+impl From<&str> for CollectorNumber {
+    fn from(number: &str) -> Self {
+        CollectorNumber(number.to_string())
+    }
+}
+
+impl From<String> for CollectorNumber {
+    fn from(number: String) -> Self {
+        CollectorNumber(number)
+    }
+}
+
+impl From<usize> for CollectorNumber {
+    fn from(number: usize) -> Self {
+        CollectorNumber(number.to_string())
+    }
+}
+
+impl AsRef<str> for CollectorNumber {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for CollectorNumber {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for CollectorNumber {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(number: &str, prefix: &str, digits: Option<u32>, suffix: &str, star: bool) {
+        let number = CollectorNumber::from(number);
+        assert_eq!(
+            (
+                number.prefix(),
+                number.number(),
+                number.suffix(),
+                number.is_star()
+            ),
+            (prefix, digits, suffix, star)
+        );
+    }
+
+    #[test]
+    fn splits_prefix_number_and_suffix() {
+        check("146", "", Some(146), "", false);
+        check("123a", "", Some(123), "a", false);
+        check("S3", "S", Some(3), "", false);
+        check("★12", "", Some(12), "", true);
+        check("75★", "", Some(75), "", true);
+        check("2021-7", "", Some(2021), "-7", false);
+        check("GR", "GR", None, "", false);
+    }
+
+    #[test]
+    fn orders_naturally() {
+        let mut numbers = ["10a", "★9", "100", "9", "S3", "10", "2", "010b", "9★"]
+            .map(CollectorNumber::from)
+            .to_vec();
+        numbers.sort();
+        assert_eq!(
+            numbers.iter().map(|n| n.as_str()).collect::<Vec<_>>(),
+            ["2", "9", "9★", "★9", "10", "10a", "010b", "100", "S3"]
+        );
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let number: CollectorNumber = serde_json::from_str(r#""★12a""#).unwrap();
+        assert_eq!(number, "★12a");
+        assert_eq!(serde_json::to_string(&number).unwrap(), r#""★12a""#);
+    }
+}
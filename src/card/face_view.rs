@@ -0,0 +1,257 @@
+use crate::card::{
+    Card, CardFace, Color, ImageUris, ParseStatValueError, ParseTypeLineError, StatValue, TypeLine,
+};
+use crate::symbology::{ManaCost, ParseManaCostError};
+
+/// One face of a card, with the fields that may be on either the card or the
+/// face resolved.
+///
+/// Multiface cards keep some of their data on each of their
+/// [`card_faces`](Card::card_faces), and some of it on the card itself: split
+/// cards have a single image and set of colors for both faces, while transform
+/// cards have one for each. A face view reads each field from wherever it is,
+/// so code can handle every layout the same way. Single faced cards have a
+/// single view, made from the card itself.
+///
+/// Face views are returned by [`Card::faces`], [`Card::front`] and
+/// [`Card::back`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[non_exhaustive]
+pub struct FaceView<'a> {
+    /// The name of this face.
+    pub name: &'a str,
+
+    /// The mana cost of this face, if it has one.
+    pub mana_cost: Option<&'a str>,
+
+    /// The type line of this face.
+    pub type_line: Option<&'a str>,
+
+    /// The Oracle text of this face, if any.
+    pub oracle_text: Option<&'a str>,
+
+    /// The power of this face, if any.
+    pub power: Option<&'a str>,
+
+    /// The toughness of this face, if any.
+    pub toughness: Option<&'a str>,
+
+    /// The loyalty of this face, if any.
+    pub loyalty: Option<&'a str>,
+
+    /// The defense of this face, if any.
+    pub defense: Option<&'a str>,
+
+    /// The colors of this face, which may be shared with the other faces.
+    pub colors: Option<&'a [Color]>,
+
+    /// The images of this face, which may be shared with the other faces.
+    pub image_uris: Option<&'a ImageUris>,
+
+    /// The card this face belongs to.
+    pub card: &'a Card,
+
+    /// The face object this view was made from, or `None` for the view of a
+    /// single faced card.
+    pub face: Option<&'a CardFace>,
+}
+
+impl<'a> FaceView<'a> {
+    /// The view of a single faced card.
+    pub(crate) fn from_card(card: &'a Card) -> Self {
+        FaceView {
+            name: &card.name,
+            mana_cost: card.mana_cost.as_deref().filter(|cost| !cost.is_empty()),
+            type_line: card.type_line.as_deref(),
+            oracle_text: card.oracle_text.as_deref(),
+            power: card.power.as_deref(),
+            toughness: card.toughness.as_deref(),
+            loyalty: card.loyalty.as_deref(),
+            defense: card.defense.as_deref(),
+            colors: card.colors.as_deref(),
+            image_uris: card.image_uris.as_ref(),
+            card,
+            face: None,
+        }
+    }
+
+    /// The view of one face of a multiface card. Only the colors and images
+    /// are taken from the card, as its other fields describe every face at
+    /// once.
+    pub(crate) fn from_face(card: &'a Card, face: &'a CardFace) -> Self {
+        FaceView {
+            name: &face.name,
+            mana_cost: Some(face.mana_cost.as_str()).filter(|cost| !cost.is_empty()),
+            type_line: face.type_line.as_deref(),
+            oracle_text: face.oracle_text.as_deref(),
+            power: face.power.as_deref(),
+            toughness: face.toughness.as_deref(),
+            loyalty: face.loyalty.as_deref(),
+            defense: face.defense.as_deref(),
+            colors: face.colors.as_deref().or(card.colors.as_deref()),
+            image_uris: face.image_uris.as_ref().or(card.image_uris.as_ref()),
+            card,
+            face: Some(face),
+        }
+    }
+
+    /// Parses the mana cost of this face, returning `None` if it has none.
+    pub fn parse_mana_cost(&self) -> Result<Option<ManaCost>, ParseManaCostError> {
+        self.mana_cost.map(str::parse).transpose()
+    }
+
+    /// Parses the type line of this face, returning `None` if it has none.
+    pub fn parse_type_line(&self) -> Result<Option<TypeLine>, ParseTypeLineError> {
+        self.type_line.map(str::parse).transpose()
+    }
+
+    /// Parses the power of this face, returning `None` if it has none.
+    pub fn parse_power(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.power.map(str::parse).transpose()
+    }
+
+    /// Parses the toughness of this face, returning `None` if it has none.
+    pub fn parse_toughness(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.toughness.map(str::parse).transpose()
+    }
+
+    /// Parses the loyalty of this face, returning `None` if it has none.
+    pub fn parse_loyalty(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.loyalty.map(str::parse).transpose()
+    }
+
+    /// Parses the defense of this face, returning `None` if it has none.
+    pub fn parse_defense(&self) -> Result<Option<StatValue>, ParseStatValueError> {
+        self.defense.map(str::parse).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::util::test_card::card;
+
+    fn image_uris(name: &str) -> serde_json::Value {
+        let uri = format!("https://cards.scryfall.io/normal/{}.jpg", name);
+        json!({
+            "small": uri, "normal": uri, "large": uri, "png": uri,
+            "art_crop": uri, "border_crop": uri,
+        })
+    }
+
+    fn face(name: &str, cost: &str, type_line: &str) -> serde_json::Value {
+        json!({
+            "object": "card_face",
+            "name": name,
+            "mana_cost": cost,
+            "type_line": type_line,
+        })
+    }
+
+    #[test]
+    fn single_faced_cards_have_one_face() {
+        let bolt = card(json!({ "image_uris": image_uris("bolt") }));
+        let faces = bolt.faces();
+        assert_eq!(faces.len(), 1);
+        assert_eq!(faces[0].name, "Lightning Bolt");
+        assert_eq!(faces[0].mana_cost, Some("{R}"));
+        assert_eq!(faces[0].colors, Some(&[Color::Red][..]));
+        assert!(faces[0].image_uris.is_some() && faces[0].face.is_none());
+        assert_eq!(bolt.front(), faces[0]);
+        assert_eq!(bolt.back(), None);
+    }
+
+    #[test]
+    fn split_faces_share_the_card_colors_and_images() {
+        let fire_ice = card(json!({
+            "name": "Fire // Ice",
+            "layout": "split",
+            "mana_cost": "{1}{R} // {1}{U}",
+            "type_line": "Instant // Instant",
+            "oracle_text": null,
+            "colors": ["U", "R"],
+            "image_uris": image_uris("fire-ice"),
+            "card_faces": [
+                face("Fire", "{1}{R}", "Instant"),
+                face("Ice", "{1}{U}", "Instant"),
+            ],
+        }));
+        let faces = fire_ice.faces();
+        assert_eq!(
+            faces
+                .iter()
+                .map(|f| (f.name, f.mana_cost))
+                .collect::<Vec<_>>(),
+            [("Fire", Some("{1}{R}")), ("Ice", Some("{1}{U}"))]
+        );
+        assert!(faces
+            .iter()
+            .all(|f| f.colors == Some(&[Color::Blue, Color::Red][..]) && f.image_uris.is_some()));
+        assert_eq!(fire_ice.front().name, "Fire");
+        assert_eq!(fire_ice.back(), None);
+    }
+
+    #[test]
+    fn double_sided_faces_have_their_own_data() {
+        let mut front = face("Delver of Secrets", "{U}", "Creature — Human Wizard");
+        front["colors"] = json!(["U"]);
+        front["image_uris"] = image_uris("delver-front");
+        let mut back = face("Insectile Aberration", "", "Creature — Human Insect");
+        back["colors"] = json!(["U"]);
+        back["power"] = json!("3");
+        back["toughness"] = json!("2");
+        back["image_uris"] = image_uris("delver-back");
+        let delver = card(json!({
+            "name": "Delver of Secrets // Insectile Aberration",
+            "layout": "transform",
+            "mana_cost": null,
+            "type_line": "Creature — Human Wizard // Creature — Human Insect",
+            "oracle_text": null,
+            "colors": null,
+            "card_faces": [front, back],
+        }));
+        let back = delver.back().unwrap();
+        assert_eq!(back.name, "Insectile Aberration");
+        assert_eq!(back.mana_cost, None);
+        assert_eq!(back.parse_power().unwrap().unwrap().value(), 3.0);
+        assert_ne!(back.image_uris, delver.front().image_uris);
+        assert_eq!(
+            delver.front().parse_mana_cost().unwrap(),
+            Some("{U}".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn parses_the_loyalty_and_defense_of_each_face() {
+        let mut front = face("Invasion of Tarkir", "{1}{R}", "Battle — Siege");
+        front["defense"] = json!("5");
+        let mut back = face("Defiant Thundermaw", "", "Creature — Dragon");
+        back["power"] = json!("4");
+        back["toughness"] = json!("4");
+        let invasion = card(json!({
+            "name": "Invasion of Tarkir // Defiant Thundermaw",
+            "layout": "transform",
+            "mana_cost": null,
+            "type_line": "Battle — Siege // Creature — Dragon",
+            "oracle_text": null,
+            "card_faces": [front, back],
+        }));
+        assert_eq!(
+            invasion.front().parse_defense().unwrap().unwrap().value(),
+            5.0
+        );
+        assert_eq!(invasion.back().unwrap().parse_defense().unwrap(), None);
+
+        let nissa = card(json!({
+            "name": "Nissa, Who Shakes the World",
+            "mana_cost": "{3}{G}{G}",
+            "type_line": "Legendary Planeswalker — Nissa",
+            "oracle_text": null,
+            "loyalty": "5",
+        }));
+        assert_eq!(nissa.front().parse_loyalty().unwrap().unwrap().value(), 5.0);
+        assert_eq!(nissa.front().parse_defense().unwrap(), None);
+    }
+}
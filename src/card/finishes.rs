@@ -2,7 +2,6 @@ use serde::{Deserialize, Serialize};
 
 /// The finish the card can come in.
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[cfg_attr(not(feature = "unknown_variants"), derive(Copy))]
 #[cfg_attr(
     all(
@@ -24,7 +23,10 @@ pub enum Finishes {
         doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
     )]
     #[cfg(feature = "unknown_variants")]
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "crate::client::schema_drift::unknown_variant::<Finishes, _>"
+    )]
     /// Unknown frame effect
     Unknown(Box<str>),
     #[cfg_attr(
@@ -14,7 +14,6 @@ use serde::{Deserialize, Serialize};
     ),
     non_exhaustive
 )]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "lowercase")]
 pub enum FrameEffect {
     /// Booster Fun
@@ -97,7 +96,10 @@ pub enum FrameEffect {
         doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
     )]
     #[cfg(feature = "unknown_variants")]
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "crate::client::schema_drift::unknown_variant::<FrameEffect, _>"
+    )]
     /// Unknown frame effect
     Unknown(Box<str>),
     #[cfg_attr(
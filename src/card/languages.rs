@@ -1,8 +1,18 @@
 use serde::{Deserialize, Serialize};
 
 /// Enum defining the languages a card can be printed in.
-#[derive(Default, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
+///
+/// For more information, refer to the [official docs](https://scryfall.com/docs/api/languages).
+#[derive(Default, Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(not(feature = "unknown_variants"), derive(Copy))]
+#[cfg_attr(
+    all(
+        not(feature = "unknown_variants"),
+        not(feature = "unknown_variants_slim")
+    ),
+    non_exhaustive
+)]
+#[allow(missing_docs)]
 pub enum Languages {
     #[default]
     #[serde(rename = "en")]
@@ -41,39 +51,33 @@ pub enum Languages {
     Phyrexian,
     #[serde(rename = "qya")]
     Quenya,
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
+    )]
+    #[cfg(feature = "unknown_variants")]
+    #[serde(
+        untagged,
+        deserialize_with = "crate::client::schema_drift::unknown_variant::<Languages, _>"
+    )]
+    /// Unknown language
+    Unknown(Box<str>),
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
+    )]
+    #[cfg(all(not(feature = "unknown_variants"), feature = "unknown_variants_slim"))]
+    #[serde(other)]
+    /// Unknown language
+    Unknown,
 }
 
 impl std::fmt::Display for Languages {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        use Languages::*;
-        write!(
-            f,
-            "{}",
-            match self {
-                English => "en",
-                Spanish => "es",
-                French => "fr",
-                German => "de",
-                Italian => "it",
-                Portuguese => "pt",
-                Japanese => "ja",
-                Korean => "ko",
-                Russian => "ru",
-                SimplifiedChinese => "zhs",
-                TraditionalChinese => "zht",
-                Hebrew => "he",
-                Latin => "la",
-                AncientGreek => "grc",
-                Arabic => "ar",
-                Sanskrit => "sa",
-                Phyrexian => "ph",
-                Quenya => "qya",
-            }
-        )
+        f.write_str(self.as_country_code())
     }
 }
 
-#[allow(dead_code)]
 impl Languages {
     /// Returns the language code as a string slice.
     pub fn as_country_code(&self) -> &str {
@@ -97,6 +101,10 @@ impl Languages {
             Sanskrit => "sa",
             Phyrexian => "ph",
             Quenya => "qya",
+            #[cfg(feature = "unknown_variants")]
+            Unknown(code) => code,
+            #[cfg(all(not(feature = "unknown_variants"), feature = "unknown_variants_slim"))]
+            Unknown => "unknown-language",
         }
     }
 
@@ -122,6 +130,57 @@ impl Languages {
             Sanskrit => "Sanskrit",
             Phyrexian => "Phyrexian",
             Quenya => "Quenya",
+            #[cfg(feature = "unknown_variants")]
+            Unknown(code) => code,
+            #[cfg(all(not(feature = "unknown_variants"), feature = "unknown_variants_slim"))]
+            Unknown => "Unknown",
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::client::{RateLimiter, ScryfallClient};
+    use crate::search::prelude::*;
+    use crate::util::test_card::{card, card_json};
+    use crate::util::test_server::TestServer;
+
+    #[test]
+    fn cards_have_a_language() {
+        assert_eq!(card(json!({})).lang, Languages::English);
+        assert_eq!(
+            card(json!({ "lang": "zhs" })).lang,
+            Languages::SimplifiedChinese
+        );
+        assert_eq!(Languages::AncientGreek.to_string(), "grc");
+        assert_eq!(Languages::AncientGreek.as_str(), "Ancient Greek");
+    }
+
+    #[test]
+    fn searches_by_language() {
+        assert_eq!(language(Languages::Japanese).to_string(), "language:ja");
+        assert_eq!(in_language(Languages::Phyrexian).to_string(), "in:ph");
+    }
+
+    #[tokio::test]
+    async fn fetches_other_languages_of_a_printing() {
+        let japanese = card_json(json!({ "lang": "ja", "printed_name": "稲妻" })).to_string();
+        let server = TestServer::start(vec![(200, japanese)]).await;
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .build()
+            .unwrap();
+
+        let bolt = card(json!({}));
+        let printing = client
+            .scope(bolt.in_language(Languages::Japanese))
+            .await
+            .unwrap();
+        assert_eq!(printing.lang, Languages::Japanese);
+        assert!(server.requests()[0].starts_with("GET /cards/m10/146/ja HTTP/1.1"));
+    }
+}
@@ -22,7 +22,6 @@ use serde::{Deserialize, Serialize};
     ),
     non_exhaustive
 )]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "snake_case")]
 pub enum Layout {
     /// A standard Magic card with one face.
@@ -76,7 +75,10 @@ pub enum Layout {
         doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
     )]
     #[cfg(feature = "unknown_variants")]
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "crate::client::schema_drift::unknown_variant::<Layout, _>"
+    )]
     /// Unknown layout
     Unknown(Box<str>),
     #[cfg_attr(
@@ -88,3 +90,22 @@ pub enum Layout {
     /// Unknown layout
     Unknown,
 }
+
+impl Layout {
+    /// Returns true if cards with this layout have faces printed on both sides,
+    /// such as transform cards. The faces of other multiface cards, like split
+    /// and flip cards, are all on the front.
+    ///
+    /// Meld cards are printed with half of their back face, which is a
+    /// separate card, so they aren't double sided.
+    pub fn is_double_sided(&self) -> bool {
+        matches!(
+            self,
+            Layout::Transform
+                | Layout::ModalDfc
+                | Layout::DoubleFacedToken
+                | Layout::ArtSeries
+                | Layout::ReversibleCard
+        )
+    }
+}
@@ -1,11 +1,15 @@
-//! Enum describing the 4 states of legality a card can have.
+//! The legality of a card in each format.
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ops::{Index, IndexMut};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::client::schema_drift;
+use crate::format::{self, Format};
 
 /// Enum describing the 4 states of legality a card can have.
 #[derive(Default, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "snake_case")]
 #[allow(missing_docs)]
 pub enum Legality {
@@ -30,6 +34,149 @@ impl PartialOrd for Legality {
     }
 }
 
+/// The legality of a card in every format.
+///
+/// Formats that are missing are not legal. Formats that Scryfall added after
+/// this version of the crate are kept, and can be found by name with
+/// [`get_by_name`](CardLegality::get_by_name). With one of the
+/// `unknown_variants` features, they're also returned as
+/// `Format::Unknown` by [`iter`](CardLegality::iter).
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::{CardLegality, Legality};
+/// use scryfall::format::Format;
+///
+/// let mut legalities = CardLegality::default();
+/// legalities[Format::Modern] = Legality::Legal;
+/// legalities[Format::Vintage] = Legality::Restricted;
+/// assert_eq!(legalities[Format::Legacy], Legality::NotLegal);
+/// assert_eq!(legalities.legal_formats().collect::<Vec<_>>(), [Format::Modern]);
+/// assert_eq!(legalities.restricted_in().collect::<Vec<_>>(), [Format::Vintage]);
+/// ```
+#[derive(Default, Clone, Eq, PartialEq, Debug)]
+pub struct CardLegality {
+    /// The legality in each known format, in the order of
+    /// [`format::KNOWN`], or `None` if Scryfall didn't list the format.
+    known: [Option<Legality>; format::KNOWN.len()],
+    /// The legality in the formats this crate doesn't know about, which is
+    /// usually empty.
+    unknown: BTreeMap<String, Legality>,
+}
+
+impl CardLegality {
+    /// The legality in the format called `name`, if Scryfall listed it.
+    pub fn get_by_name(&self, name: &str) -> Option<Legality> {
+        match format::known_index(name) {
+            Some(index) => self.known[index],
+            None => self.unknown.get(name).copied(),
+        }
+    }
+
+    /// Iterates over the formats and their legalities: the formats this crate
+    /// knows about first, in the order they're declared in [`Format`], then the
+    /// others in the order of their names.
+    ///
+    /// Formats this crate doesn't know about are skipped, unless one of the
+    /// `unknown_variants` features is enabled. See
+    /// [`iter_by_name`](CardLegality::iter_by_name) for every format.
+    pub fn iter(&self) -> impl Iterator<Item = (Format, Legality)> + '_ {
+        self.iter_by_name()
+            .filter_map(|(name, legality)| Some((name.parse().ok()?, legality)))
+    }
+
+    /// Iterates over the names of the formats and their legalities, including
+    /// the formats this crate doesn't know about, in the same order as
+    /// [`iter`](CardLegality::iter).
+    pub fn iter_by_name(&self) -> impl Iterator<Item = (&str, Legality)> + '_ {
+        let known = format::KNOWN
+            .iter()
+            .zip(&self.known)
+            .filter_map(|(format, legality)| Some((format.as_str(), (*legality)?)));
+        let unknown = self
+            .unknown
+            .iter()
+            .map(|(name, legality)| (name.as_str(), *legality));
+        known.chain(unknown)
+    }
+
+    /// The formats the card is legal in, not counting the ones it's
+    /// restricted in.
+    pub fn legal_formats(&self) -> impl Iterator<Item = Format> + '_ {
+        self.formats_where(Legality::Legal)
+    }
+
+    /// The formats the card is restricted in.
+    pub fn restricted_in(&self) -> impl Iterator<Item = Format> + '_ {
+        self.formats_where(Legality::Restricted)
+    }
+
+    /// The formats the card is banned in.
+    pub fn banned_in(&self) -> impl Iterator<Item = Format> + '_ {
+        self.formats_where(Legality::Banned)
+    }
+
+    fn formats_where(&self, wanted: Legality) -> impl Iterator<Item = Format> + '_ {
+        self.iter()
+            .filter(move |(_, legality)| *legality == wanted)
+            .map(|(format, _)| format)
+    }
+}
+
+impl Serialize for CardLegality {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter_by_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for CardLegality {
+    /// Deserializes the legalities, recording the formats this crate doesn't
+    /// know about as schema drift.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut legalities = CardLegality::default();
+        for (name, legality) in BTreeMap::<String, Legality>::deserialize(deserializer)? {
+            match format::known_index(&name) {
+                Some(index) => legalities.known[index] = Some(legality),
+                None => {
+                    schema_drift::record_variant::<Format>(&name);
+                    legalities.unknown.insert(name, legality);
+                },
+            }
+        }
+        Ok(legalities)
+    }
+}
+
+impl Index<Format> for CardLegality {
+    type Output = Legality;
+
+    fn index(&self, index: Format) -> &Self::Output {
+        let legality = match format::known_index(index.as_str()) {
+            Some(index) => self.known[index].as_ref(),
+            None => self.unknown.get(index.as_str()),
+        };
+        legality.unwrap_or(&Legality::NotLegal)
+    }
+}
+
+impl IndexMut<Format> for CardLegality {
+    /// # Panics
+    ///
+    /// With the `unknown_variants_slim` feature, panics if `index` is
+    /// `Format::Unknown`, since it doesn't say which format to set.
+    fn index_mut(&mut self, index: Format) -> &mut Self::Output {
+        #[cfg(all(not(feature = "unknown_variants"), feature = "unknown_variants_slim"))]
+        assert!(
+            index != Format::Unknown,
+            "can't set the legality of a format without its name"
+        );
+        match format::known_index(index.as_str()) {
+            Some(index) => self.known[index].get_or_insert_with(Legality::default),
+            None => self.unknown.entry(index.as_str().to_string()).or_default(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -62,4 +209,51 @@ mod tests {
             assert_eq!(&a.partial_cmp(b), order);
         }
     }
+
+    #[test]
+    fn keeps_formats_it_does_not_know() {
+        let legalities: CardLegality = serde_json::from_value(serde_json::json!({
+            "modern": "legal",
+            "vintage": "restricted",
+            "legacy": "banned",
+            "spellslinger": "legal",
+        }))
+        .unwrap();
+        assert_eq!(legalities[Format::Legacy], Legality::Banned);
+        assert_eq!(legalities[Format::Pauper], Legality::NotLegal);
+        assert_eq!(
+            legalities.get_by_name("spellslinger"),
+            Some(Legality::Legal)
+        );
+        assert_eq!(legalities.get_by_name("pauper"), None);
+        assert_eq!(legalities.iter_by_name().count(), 4);
+        assert_eq!(legalities.banned_in().collect::<Vec<_>>(), [Format::Legacy]);
+
+        #[cfg(not(any(feature = "unknown_variants", feature = "unknown_variants_slim")))]
+        assert_eq!(
+            legalities.legal_formats().collect::<Vec<_>>(),
+            [Format::Modern]
+        );
+        #[cfg(feature = "unknown_variants")]
+        assert_eq!(
+            legalities.legal_formats().collect::<Vec<_>>(),
+            [Format::Modern, Format::Unknown("spellslinger".into())]
+        );
+        #[cfg(all(not(feature = "unknown_variants"), feature = "unknown_variants_slim"))]
+        assert_eq!(
+            legalities.legal_formats().collect::<Vec<_>>(),
+            [Format::Modern, Format::Unknown]
+        );
+
+        let json = serde_json::to_value(&legalities).unwrap();
+        assert_eq!(json["spellslinger"], "legal");
+    }
+
+    #[test]
+    #[cfg(all(not(feature = "unknown_variants"), feature = "unknown_variants_slim"))]
+    #[should_panic = "can't set the legality of a format without its name"]
+    fn cannot_set_the_legality_of_unknown_formats() {
+        let mut legalities = CardLegality::default();
+        legalities[Format::Unknown] = Legality::Legal;
+    }
 }
@@ -5,9 +5,12 @@ use url::Url;
 
 /// Struct describing card preview information.
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug, Default)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct Preview {
     /// The date this card was previewed.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub previewed_at: Option<NaiveDate>,
 
     /// A link to the preview for this card.
@@ -16,8 +19,25 @@ pub struct Preview {
     /// deserialization to fail. If this happens, a `None` variant is used
     /// instead.
     #[serde(deserialize_with = "crate::util::deserialize_or_none")]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub source_uri: Option<Url>,
 
     /// The name of the source that previewed this card.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub source: Option<String>,
+
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<Preview, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
 }
@@ -1,11 +1,20 @@
 //! Module defining a price object containing data in various currencies.
 use std::cmp::Ordering;
+use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
+use crate::card::Finishes;
+
 /// Struct defining a price object containing data in various currencies.
+///
+/// Use [`Price::get`] to read a price as [`Money`] instead of as text.
+///
+/// Prices have a total ordering: they're compared by their amounts in USD,
+/// then in EUR, then in TIX, nonfoil before foil before etched, with a missing
+/// amount being less than any other. Amounts that are equal but written
+/// differently, like `1.5` and `1.50`, are ordered by their text.
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug, Default)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[allow(missing_docs)]
 #[non_exhaustive]
 pub struct Price {
@@ -15,64 +24,249 @@ pub struct Price {
     pub eur_foil: Option<String>,
     pub tix: Option<String>,
     pub usd_etched: Option<String>,
+
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<Price, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
 }
 
 impl Price {
-    /// Creates an array of component prices that can be iterated over.
-    fn to_array(&self) -> [&Option<String>; 5] {
+    /// The price of the given finish in `currency`, if Scryfall has one.
+    ///
+    /// Scryfall tracks a single price in TIX, which is returned for every
+    /// finish. There are no etched prices in EUR.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Currency, Finishes, Money, Price};
+    ///
+    /// let mut price = Price::default();
+    /// price.usd_foil = Some("24.99".to_string());
+    /// assert_eq!(
+    ///     price.get(Currency::Usd, Finishes::Foil),
+    ///     Some(Money::parse(Currency::Usd, "24.99").unwrap())
+    /// );
+    /// assert_eq!(price.get(Currency::Usd, Finishes::Nonfoil), None);
+    /// ```
+    pub fn get(&self, currency: Currency, finish: Finishes) -> Option<Money> {
+        self.money(currency, &finish)
+    }
+
+    /// The finish that's cheapest in `currency` out of `finishes`, and its
+    /// price. Finishes without a price are skipped.
+    ///
+    /// Use [`Card::cheapest_price`](crate::Card::cheapest_price) to check
+    /// every finish a card comes in.
+    pub fn cheapest(
+        &self,
+        currency: Currency,
+        finishes: impl IntoIterator<Item = Finishes>,
+    ) -> Option<(Finishes, Money)> {
+        finishes
+            .into_iter()
+            .filter_map(|finish| Some((self.money(currency, &finish)?, finish)))
+            .min_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(money, finish)| (finish, money))
+    }
+
+    fn money(&self, currency: Currency, finish: &Finishes) -> Option<Money> {
+        Money::parse(currency, self.field(currency, finish)?.as_deref()?).ok()
+    }
+
+    /// The field holding the price of `finish` in `currency`.
+    fn field(&self, currency: Currency, finish: &Finishes) -> Option<&Option<String>> {
+        Some(match (currency, finish) {
+            (Currency::Usd, Finishes::Nonfoil) => &self.usd,
+            (Currency::Usd, Finishes::Foil) => &self.usd_foil,
+            (Currency::Usd, Finishes::Etched) => &self.usd_etched,
+            (Currency::Eur, Finishes::Nonfoil) => &self.eur,
+            (Currency::Eur, Finishes::Foil) => &self.eur_foil,
+            (Currency::Tix, _) => &self.tix,
+            _ => return None,
+        })
+    }
+
+    /// The fields of this price in the order they're compared in.
+    fn to_array(&self) -> [(Currency, &Option<String>); 6] {
         [
-            &self.usd,
-            &self.usd_foil,
-            &self.eur,
-            &self.eur_foil,
-            &self.tix,
+            (Currency::Usd, &self.usd),
+            (Currency::Usd, &self.usd_foil),
+            (Currency::Usd, &self.usd_etched),
+            (Currency::Eur, &self.eur),
+            (Currency::Eur, &self.eur_foil),
+            (Currency::Tix, &self.tix),
         ]
     }
 }
 
-/// Compares two prices as floating-point numbers.
-fn compare_prices(a: &Option<String>, b: &Option<String>) -> Option<Ordering> {
-    if let (Some(a), Some(b)) = (a, b) {
-        if let (Ok(a), Ok(b)) = (a.parse::<f32>(), b.parse()) {
-            return a.partial_cmp(&b);
-        }
+impl Ord for Price {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let amounts = |price: &Price| {
+            price.to_array().map(|(currency, amount)| {
+                amount
+                    .as_deref()
+                    .and_then(|amount| Money::parse(currency, amount).ok())
+            })
+        };
+        let ordering = amounts(self)
+            .cmp(&amounts(other))
+            .then_with(|| self.to_array().cmp(&other.to_array()));
+        #[cfg(feature = "extra_fields")]
+        let ordering = ordering.then_with(|| self.extra.cmp(&other.extra));
+        ordering
     }
-    None
 }
 
 impl PartialOrd for Price {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let mut result = None;
-        for (a, b) in self.to_array().iter().zip(other.to_array().iter()) {
-            match (result, compare_prices(a, b)) {
-                // If either ordering is `None`, use the other. Then if either is `Some(Equal)`,
-                // use the other.
-                (None, order)
-                | (order, None)
-                | (Some(Ordering::Equal), order)
-                | (order, Some(Ordering::Equal)) => {
-                    result = order;
-                },
-                // If the two orderings already agree, do nothing.
-                (Some(a), Some(b)) if a == b => {},
-                // Otherwise, they disagree, so these prices cannot be ordered.
-                _ => return None,
-            }
+        Some(self.cmp(other))
+    }
+}
+
+/// A currency Scryfall has prices in.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Currency {
+    /// US dollars, from TCGplayer.
+    Usd,
+    /// Euros, from Cardmarket.
+    Eur,
+    /// Magic Online event tickets, from Cardhoarder.
+    Tix,
+}
+
+/// An exact amount of money in a [`Currency`].
+///
+/// Amounts are kept as decimals, so they can be compared without the rounding
+/// errors of floating point numbers. Amounts of different currencies aren't
+/// converted: they're ordered by currency first.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::{Currency, Money};
+///
+/// let price = Money::parse(Currency::Eur, "1.5").unwrap();
+/// assert_eq!(price, Money::parse(Currency::Eur, "1.50").unwrap());
+/// assert!(price < Money::parse(Currency::Eur, "10").unwrap());
+/// assert_eq!(price.to_string(), "€1.50");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Money {
+    currency: Currency,
+    /// The amount, without its decimal point.
+    digits: u64,
+    /// How many of `digits` are after the decimal point. Trailing zeros after
+    /// the decimal point are removed, so equal amounts are always written the
+    /// same way.
+    scale: u32,
+}
+
+impl Money {
+    /// The most digits an amount may have after its decimal point.
+    const MAX_SCALE: u32 = 9;
+
+    /// Parses an amount of `currency`, like `1.99`.
+    pub fn parse(currency: Currency, amount: &str) -> Result<Self, ParseMoneyError> {
+        let error = || ParseMoneyError {
+            amount: amount.to_string(),
+        };
+        let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+        let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+            return Err(error());
+        }
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > Self::MAX_SCALE as usize {
+            return Err(error());
+        }
+        let scale = fraction.len() as u32;
+        let digits = format!("{}{}", whole, fraction);
+        let digits = match digits.trim_start_matches('0') {
+            "" => 0,
+            digits => digits.parse().map_err(|_| error())?,
+        };
+        Ok(Money {
+            currency,
+            digits,
+            scale,
+        })
+    }
+
+    /// The currency of this amount.
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// The amount as a floating point number, which may not be exact.
+    pub fn to_f64(&self) -> f64 {
+        self.digits as f64 / 10f64.powi(self.scale as i32)
+    }
+
+    /// The digits of the amount with `scale` digits after the decimal point.
+    fn scaled(&self, scale: u32) -> u128 {
+        u128::from(self.digits) * 10u128.pow(scale - self.scale)
+    }
+}
+
+impl Ord for Money {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        self.currency
+            .cmp(&other.currency)
+            .then_with(|| self.scaled(scale).cmp(&other.scaled(scale)))
+    }
+}
+
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Money {
+    /// Writes the amount with at least two decimal places, and the symbol of
+    /// its currency.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scale = self.scale.max(2);
+        let digits = format!("{:0width$}", self.scaled(scale), width = scale as usize + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - scale as usize);
+        match self.currency {
+            Currency::Usd => write!(f, "${}.{}", whole, fraction),
+            Currency::Eur => write!(f, "€{}.{}", whole, fraction),
+            Currency::Tix => write!(f, "{}.{} tix", whole, fraction),
         }
-        result
     }
 }
 
+/// The error returned when an amount of money can't be parsed.
+#[derive(Clone, Eq, PartialEq, Debug, thiserror::Error)]
+#[error("invalid amount of money {amount:?}")]
+pub struct ParseMoneyError {
+    amount: String,
+}
+
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::*;
+    use crate::util::test_card::card;
+
+    fn usd(amount: &str) -> Money {
+        Money::parse(Currency::Usd, amount).unwrap()
+    }
 
     #[test]
     fn no_prices() {
         let a = Price::default();
         let b = Price::default();
 
-        assert_eq!(a.partial_cmp(&b), None);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
     }
 
     #[test]
@@ -90,7 +284,7 @@ mod tests {
             ..Default::default()
         };
 
-        assert_eq!(a.partial_cmp(&b), Some(Ordering::Less));
+        assert_eq!(a.cmp(&b), Ordering::Less);
     }
 
     #[test]
@@ -106,7 +300,9 @@ mod tests {
             ..Default::default()
         };
 
-        assert_eq!(a.partial_cmp(&b), None);
+        // USD is compared before TIX.
+        assert_eq!(a.cmp(&b), Ordering::Less);
+        assert_eq!(b.cmp(&a), Ordering::Greater);
     }
 
     #[test]
@@ -118,10 +314,72 @@ mod tests {
         };
         let b = Price {
             usd: Some("3.99".to_string()),
-            eur: Some("4.20".to_string()),
+            tix: Some("2.10".to_string()),
             ..Default::default()
         };
 
-        assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+        // Equal amounts written differently are ordered by their text, so the
+        // ordering agrees with `Eq`.
+        assert_eq!(a.cmp(&b), Ordering::Less);
+        assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+        assert_eq!(
+            a.get(Currency::Tix, Finishes::Nonfoil),
+            b.get(Currency::Tix, Finishes::Nonfoil)
+        );
+    }
+
+    #[test]
+    fn parses_exact_amounts() {
+        assert_eq!(usd("0.1"), usd(".10"));
+        assert_eq!(usd("0"), usd("0.00"));
+        assert!(usd("0.1") < usd("0.11"));
+        assert!(usd("9.99") < usd("10"));
+        assert_eq!(usd("0.1").to_string(), "$0.10");
+        assert_eq!(usd("1234.567").to_string(), "$1234.567");
+        assert_eq!(
+            Money::parse(Currency::Tix, "0.03").unwrap().to_string(),
+            "0.03 tix"
+        );
+        assert!(
+            Money::parse(Currency::Usd, "1.99").unwrap()
+                < Money::parse(Currency::Eur, "0.01").unwrap()
+        );
+        for amount in ["", ".", "-1", "1,99", "1.2.3", "$1", "99999999999999999999"] {
+            assert!(Money::parse(Currency::Usd, amount).is_err(), "{:?}", amount);
+        }
+    }
+
+    #[test]
+    fn gets_the_price_of_each_finish() {
+        let bolt = card(json!({}));
+        let prices = &bolt.prices;
+        assert_eq!(
+            prices.get(Currency::Usd, Finishes::Nonfoil),
+            Some(usd("1.99"))
+        );
+        assert_eq!(
+            prices.get(Currency::Usd, Finishes::Foil),
+            Some(usd("24.99"))
+        );
+        assert_eq!(prices.get(Currency::Usd, Finishes::Etched), None);
+        assert_eq!(prices.get(Currency::Eur, Finishes::Etched), None);
+        assert_eq!(
+            prices.get(Currency::Tix, Finishes::Foil),
+            Money::parse(Currency::Tix, "0.03").ok()
+        );
+
+        assert_eq!(
+            bolt.cheapest_price(Currency::Eur),
+            Some((
+                Finishes::Nonfoil,
+                Money::parse(Currency::Eur, "1.50").unwrap()
+            ))
+        );
+        let foil_only = card(json!({ "finishes": ["foil", "etched"] }));
+        assert_eq!(
+            foil_only.cheapest_price(Currency::Usd),
+            Some((Finishes::Foil, usd("24.99")))
+        );
+        assert_eq!(prices.cheapest(Currency::Usd, [Finishes::Etched]), None);
     }
 }
@@ -10,7 +10,6 @@ use serde::{Deserialize, Serialize};
     ),
     non_exhaustive
 )]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "lowercase")]
 #[allow(missing_docs)]
 pub enum PromoType {
@@ -128,7 +127,10 @@ pub enum PromoType {
         doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
     )]
     #[cfg(feature = "unknown_variants")]
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "crate::client::schema_drift::unknown_variant::<PromoType, _>"
+    )]
     /// Unknown variant
     Unknown(Box<str>),
     #[cfg_attr(
@@ -3,12 +3,32 @@ use url::Url;
 
 /// Enum defining the types of marketplace URIs available for purchasing cards.
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug, Default)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct PurchaseUris {
     #[serde(rename = "tcgplayer")]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub tcg_player: Option<Url>,
     #[serde(rename = "cardmarket")]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub card_market: Option<Url>,
     #[serde(rename = "cardhoarder")]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub card_hoarder: Option<Url>,
+
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<PurchaseUris, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
 }
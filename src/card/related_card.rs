@@ -10,7 +10,6 @@ use crate::uri::Uri;
 ///
 /// For more information, refer to the [official docs](https://scryfall.com/api/cards#related-card-objects).
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct RelatedCard {
     /// An unique ID for this card in Scryfall’s database.
@@ -28,6 +27,15 @@ pub struct RelatedCard {
     /// The name of this particular related card.
     pub uri: Uri<Card>,
 
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<RelatedCard, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
+
     #[cfg(test)]
     #[serde(rename = "object")]
     _object: String,
@@ -35,7 +43,6 @@ pub struct RelatedCard {
 
 /// The kind of related card.
 #[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 #[allow(missing_docs)]
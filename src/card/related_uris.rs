@@ -2,13 +2,37 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug, Default)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "lowercase")]
 pub struct RelatedUris {
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub gatherer: Option<Url>,
     #[serde(rename = "tcgplayer_infinite_articles")]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub tcg_player_infinite_articles: Option<Url>,
     #[serde(rename = "tcgplayer_infinite_decks")]
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub tcg_player_infinite_decks: Option<Url>,
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub edhrec: Option<Url>,
+
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<RelatedUris, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
 }
@@ -2,7 +2,6 @@ use serde::{Deserialize, Serialize};
 
 /// The security stamp on this card, if any.
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[cfg_attr(
     all(
         not(feature = "unknown_variants"),
@@ -25,7 +24,10 @@ pub enum SecurityStamp {
         doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
     )]
     #[cfg(feature = "unknown_variants")]
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "crate::client::schema_drift::unknown_variant::<SecurityStamp, _>"
+    )]
     /// Unknown frame effect
     Unknown(Box<str>),
     #[cfg_attr(
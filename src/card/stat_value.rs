@@ -0,0 +1,279 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+/// One of the terms added together to make a [`StatValue`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum Term {
+    Number(f32),
+    Star,
+    StarSquared,
+    X,
+    Unknown,
+    Infinity,
+}
+
+impl Term {
+    fn value(self) -> f32 {
+        match self {
+            Term::Number(n) => n,
+            Term::Infinity => f32::INFINITY,
+            Term::Star | Term::StarSquared | Term::X | Term::Unknown => 0.0,
+        }
+    }
+
+    fn parse(term: &str) -> Option<Term> {
+        Some(match term {
+            "*" => Term::Star,
+            "*²" => Term::StarSquared,
+            "X" | "x" => Term::X,
+            "?" => Term::Unknown,
+            "∞" => Term::Infinity,
+            "½" => Term::Number(0.5),
+            _ => {
+                let (whole, half) = match term.strip_suffix('½') {
+                    Some(whole) => (whole, 0.5),
+                    None => (term, 0.0),
+                };
+                let valid = !whole.is_empty()
+                    && whole.chars().all(|c| c.is_ascii_digit() || c == '.')
+                    && whole.starts_with(|c: char| c.is_ascii_digit() || c == '.');
+                if !valid {
+                    return None;
+                }
+                Term::Number(whole.parse::<f32>().ok()? + half)
+            },
+        })
+    }
+}
+
+/// The power, toughness, loyalty or defense of a card.
+///
+/// These are usually numbers, but may also be variable, like `*`, `1+*` or
+/// `X`, or something stranger from a funny card, like `?`, `∞` or `3½`. The
+/// original text is always kept, so a stat displays exactly as Scryfall wrote
+/// it.
+///
+/// For comparisons, a stat is evaluated like in Scryfall's search: `*`, `X`
+/// and `?` count as 0, so `1+*` is worth 1. Stats are ordered by their value,
+/// and then by their text.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::StatValue;
+///
+/// let power: StatValue = "1+*".parse().unwrap();
+/// assert_eq!(power.value(), 1.0);
+/// assert!(power.is_variable());
+/// assert_eq!(power.to_string(), "1+*");
+///
+/// let toughness: StatValue = "3".parse().unwrap();
+/// assert_eq!(toughness.as_number(), Some(3.0));
+/// assert!(power < toughness);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StatValue {
+    text: String,
+    terms: Vec<Term>,
+}
+
+impl StatValue {
+    /// The stat as Scryfall wrote it.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// The value of the stat, counting `*`, `X` and `?` as 0.
+    pub fn value(&self) -> f32 {
+        self.terms.iter().map(|term| term.value()).sum()
+    }
+
+    /// The value of the stat, if it doesn't depend on the game, such as `3`
+    /// or `-1`.
+    pub fn as_number(&self) -> Option<f32> {
+        (!self.is_variable()).then(|| self.value())
+    }
+
+    /// Returns true if the value of the stat depends on the game, such as `*`
+    /// or `X`.
+    pub fn is_variable(&self) -> bool {
+        self.terms.iter().any(|term| {
+            matches!(
+                term,
+                Term::Star | Term::StarSquared | Term::X | Term::Unknown
+            )
+        })
+    }
+}
+
+impl PartialEq for StatValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for StatValue {}
+
+impl Hash for StatValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.hash(state);
+    }
+}
+
+impl Ord for StatValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value()
+            .total_cmp(&other.value())
+            .then_with(|| self.text.cmp(&other.text))
+    }
+}
+
+impl PartialOrd for StatValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for StatValue {
+    type Err = ParseStatValueError;
+
+    /// Parses a stat such as `3`, `-1`, `*` or `1+*`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseStatValueError {
+            stat: s.to_string(),
+        };
+        let mut terms = Vec::new();
+        let mut rest = s.trim();
+        let mut sign = 1.0;
+        if let Some(negative) = rest.strip_prefix('-') {
+            (rest, sign) = (negative, -1.0);
+        } else if let Some(positive) = rest.strip_prefix('+') {
+            rest = positive;
+        }
+        loop {
+            let end = rest.find(['+', '-']).unwrap_or(rest.len());
+            let term = match Term::parse(&rest[..end]).ok_or_else(error)? {
+                Term::Number(n) => Term::Number(sign * n),
+                Term::Infinity if sign < 0.0 => Term::Number(f32::NEG_INFINITY),
+                term => term,
+            };
+            terms.push(term);
+            match rest[end..].chars().next() {
+                Some(op) => {
+                    sign = if op == '-' { -1.0 } else { 1.0 };
+                    rest = &rest[end + 1..];
+                },
+                None => break,
+            }
+        }
+        Ok(StatValue {
+            text: s.to_string(),
+            terms,
+        })
+    }
+}
+
+impl fmt::Display for StatValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl Serialize for StatValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.text)
+    }
+}
+
+impl<'de> Deserialize<'de> for StatValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// The error returned when a power, toughness, loyalty or defense can't be
+/// parsed.
+#[derive(Clone, Eq, PartialEq, Debug, thiserror::Error)]
+#[error("invalid stat {stat:?}")]
+pub struct ParseStatValueError {
+    stat: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::util::test_card::card;
+
+    fn stat(stat: &str) -> StatValue {
+        stat.parse().unwrap()
+    }
+
+    #[test]
+    fn evaluates_numbers_and_variables() {
+        let cases = [
+            ("3", 3.0, false),
+            ("-1", -1.0, false),
+            ("+2", 2.0, false),
+            ("*", 0.0, true),
+            ("1+*", 1.0, true),
+            ("*+1", 1.0, true),
+            ("7-*", 7.0, true),
+            ("*²", 0.0, true),
+            ("X", 0.0, true),
+            ("?", 0.0, true),
+            ("3½", 3.5, false),
+            ("½", 0.5, false),
+            ("1.5", 1.5, false),
+            ("∞", f32::INFINITY, false),
+        ];
+        for (text, value, variable) in cases {
+            let stat = stat(text);
+            assert_eq!(
+                (stat.value(), stat.is_variable()),
+                (value, variable),
+                "{}",
+                text
+            );
+            assert_eq!(stat.to_string(), text);
+        }
+        for text in ["", "+", "1+", "two", "1..2", "*-", "-.-"] {
+            assert!(text.parse::<StatValue>().is_err(), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn orders_by_value_then_text() {
+        let mut stats = ["∞", "2", "*", "1+*", "-1", "0", "1", "X", "½"].map(stat);
+        stats.sort();
+        assert_eq!(
+            stats.iter().map(StatValue::as_str).collect::<Vec<_>>(),
+            ["-1", "*", "0", "X", "½", "1", "1+*", "2", "∞"]
+        );
+    }
+
+    #[test]
+    fn parses_card_stats() {
+        let tarmogoyf = card(json!({ "power": "*", "toughness": "1+*" }));
+        assert_eq!(tarmogoyf.parse_power().unwrap(), Some(stat("*")));
+        assert_eq!(tarmogoyf.parse_toughness().unwrap().unwrap().value(), 1.0);
+        assert_eq!(tarmogoyf.parse_loyalty().unwrap(), None);
+        assert!(card(json!({ "defense": "five" })).parse_defense().is_err());
+
+        assert_eq!(serde_json::to_value(stat("1+*")).unwrap(), json!("1+*"));
+    }
+}
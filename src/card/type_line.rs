@@ -0,0 +1,654 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::catalog::Catalog;
+
+/// Subtypes made of more than one word. These are recognized without a
+/// [`SubtypeCatalog`], which knows every subtype.
+const MULTI_WORD_SUBTYPES: &[&str] = &["Time Lord"];
+
+macro_rules! type_words {
+    ($(#[$attr:meta])* $name:ident { $($(#[$doc:meta])* $variant:ident),* $(,)? }) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+        #[non_exhaustive]
+        pub enum $name {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl $name {
+            /// The word for this type, as it appears on a type line.
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $($name::$variant => stringify!($variant),)*
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseTypeLineError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(stringify!($variant) => Ok($name::$variant),)*
+                    _ => Err(ParseTypeLineError::new(
+                        s,
+                        concat!("not a ", stringify!($name)).to_lowercase(),
+                    )),
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+type_words! {
+    /// A supertype, written before the card types of a type line.
+    Supertype {
+        /// Basic lands.
+        Basic,
+        /// Elite, used on some funny cards.
+        Elite,
+        /// Host, used on some funny cards.
+        Host,
+        /// Legendary permanents and spells.
+        Legendary,
+        /// Ongoing schemes.
+        Ongoing,
+        /// Snow permanents.
+        Snow,
+        /// Tokens.
+        Token,
+        /// World enchantments.
+        World,
+    }
+}
+
+/// A card type, such as creature or instant.
+///
+/// Card types this crate doesn't know about can only be parsed with one of the
+/// `unknown_variants` features.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(not(feature = "unknown_variants"), derive(Copy))]
+#[cfg_attr(
+    all(
+        not(feature = "unknown_variants"),
+        not(feature = "unknown_variants_slim")
+    ),
+    non_exhaustive
+)]
+pub enum CardType {
+    /// Artifacts.
+    Artifact,
+    /// Battles.
+    Battle,
+    /// Conspiracies.
+    Conspiracy,
+    /// Creatures.
+    Creature,
+    /// Dungeons.
+    Dungeon,
+    /// Emblems.
+    Emblem,
+    /// Enchantments.
+    Enchantment,
+    /// Heroes, from the Theros challenge decks.
+    Hero,
+    /// Instants.
+    Instant,
+    /// Kindred cards, which have creature types.
+    Kindred,
+    /// Lands.
+    Land,
+    /// Phenomena, from Planechase.
+    Phenomenon,
+    /// Planes, from Planechase.
+    Plane,
+    /// Planeswalkers.
+    Planeswalker,
+    /// Schemes, from Archenemy.
+    Scheme,
+    /// Sorceries.
+    Sorcery,
+    /// The old name of kindred.
+    Tribal,
+    /// Vanguards.
+    Vanguard,
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
+    )]
+    #[cfg(feature = "unknown_variants")]
+    /// Unknown card type
+    Unknown(Box<str>),
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
+    )]
+    #[cfg(all(not(feature = "unknown_variants"), feature = "unknown_variants_slim"))]
+    /// Unknown card type
+    Unknown,
+}
+
+/// Every card type this crate knows about.
+const KNOWN_CARD_TYPES: [CardType; 18] = [
+    CardType::Artifact,
+    CardType::Battle,
+    CardType::Conspiracy,
+    CardType::Creature,
+    CardType::Dungeon,
+    CardType::Emblem,
+    CardType::Enchantment,
+    CardType::Hero,
+    CardType::Instant,
+    CardType::Kindred,
+    CardType::Land,
+    CardType::Phenomenon,
+    CardType::Plane,
+    CardType::Planeswalker,
+    CardType::Scheme,
+    CardType::Sorcery,
+    CardType::Tribal,
+    CardType::Vanguard,
+];
+
+impl CardType {
+    /// The word for this type, as it appears on a type line.
+    pub fn as_str(&self) -> &str {
+        use CardType::*;
+        match self {
+            Artifact => "Artifact",
+            Battle => "Battle",
+            Conspiracy => "Conspiracy",
+            Creature => "Creature",
+            Dungeon => "Dungeon",
+            Emblem => "Emblem",
+            Enchantment => "Enchantment",
+            Hero => "Hero",
+            Instant => "Instant",
+            Kindred => "Kindred",
+            Land => "Land",
+            Phenomenon => "Phenomenon",
+            Plane => "Plane",
+            Planeswalker => "Planeswalker",
+            Scheme => "Scheme",
+            Sorcery => "Sorcery",
+            Tribal => "Tribal",
+            Vanguard => "Vanguard",
+            #[cfg(feature = "unknown_variants")]
+            Unknown(word) => word,
+            #[cfg(all(not(feature = "unknown_variants"), feature = "unknown_variants_slim"))]
+            Unknown => "Unknown",
+        }
+    }
+
+    /// The card type whose subtypes this one uses. Kindred cards use creature
+    /// types, and instants share their spell types with sorceries.
+    fn subtype_kind(&self) -> CardType {
+        match self {
+            CardType::Kindred | CardType::Tribal => CardType::Creature,
+            CardType::Instant => CardType::Sorcery,
+            other => other.to_owned(),
+        }
+    }
+}
+
+impl FromStr for CardType {
+    type Err = ParseTypeLineError;
+
+    /// Parses a card type as it appears on a type line, like `Creature`. Words
+    /// that aren't a card type this crate knows about are only accepted with
+    /// one of the `unknown_variants` features.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(card_type) = KNOWN_CARD_TYPES.iter().find(|t| t.as_str() == s).cloned() {
+            return Ok(card_type);
+        }
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "unknown_variants")] {
+                Ok(CardType::Unknown(s.into()))
+            } else if #[cfg(feature = "unknown_variants_slim")] {
+                Ok(CardType::Unknown)
+            } else {
+                Err(ParseTypeLineError::new(s, "not a cardtype".to_string()))
+            }
+        }
+    }
+}
+
+impl fmt::Display for CardType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The types of a single face of a card, as written on its type line.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct FaceTypes {
+    /// The supertypes, such as legendary.
+    pub supertypes: Vec<Supertype>,
+    /// The card types, such as creature.
+    pub types: Vec<CardType>,
+    /// The subtypes, written after the dash, such as `Human Wizard`.
+    pub subtypes: Vec<String>,
+}
+
+impl FaceTypes {
+    /// Returns true if this face has the given card type.
+    pub fn has_type(&self, card_type: CardType) -> bool {
+        self.types.contains(&card_type)
+    }
+
+    /// Returns true if this face has the given supertype.
+    pub fn has_supertype(&self, supertype: Supertype) -> bool {
+        self.supertypes.contains(&supertype)
+    }
+
+    /// Returns true if this face has the given subtype. The comparison
+    /// ignores case.
+    pub fn has_subtype(&self, subtype: &str) -> bool {
+        self.subtypes
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(subtype))
+    }
+
+    fn parse(face: &str, is_subtype: &dyn Fn(&str) -> bool) -> Result<Self, &'static str> {
+        let (types, subtypes) = match face.split_once('—') {
+            Some((types, subtypes)) => (types, subtypes),
+            None => (face, ""),
+        };
+        let mut parsed = FaceTypes::default();
+        for word in types.split_whitespace() {
+            if let Ok(supertype) = word.parse() {
+                parsed.supertypes.push(supertype);
+            } else if let Ok(card_type) = word.parse() {
+                parsed.types.push(card_type);
+            } else {
+                return Err("unknown supertype or card type");
+            }
+        }
+        if parsed.types.is_empty() && parsed.supertypes.is_empty() {
+            return Err("a face must have at least one type");
+        }
+
+        let words = subtypes.split_whitespace().collect::<Vec<_>>();
+        let mut i = 0;
+        while i < words.len() {
+            let pair = words
+                .get(i + 1)
+                .map(|next| format!("{} {}", words[i], next));
+            match pair.filter(|pair| is_subtype(pair)) {
+                Some(pair) => {
+                    parsed.subtypes.push(pair);
+                    i += 2;
+                },
+                None => {
+                    parsed.subtypes.push(words[i].to_string());
+                    i += 1;
+                },
+            }
+        }
+        if parsed.subtypes.is_empty() && face.contains('—') {
+            return Err("expected subtypes after the dash");
+        }
+        Ok(parsed)
+    }
+}
+
+impl fmt::Display for FaceTypes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut words = self
+            .supertypes
+            .iter()
+            .map(|s| s.as_str())
+            .chain(self.types.iter().map(|t| t.as_str()));
+        if let Some(first) = words.next() {
+            f.write_str(first)?;
+        }
+        words.try_for_each(|word| write!(f, " {}", word))?;
+        if !self.subtypes.is_empty() {
+            write!(f, " — {}", self.subtypes.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// A parsed type line, with the supertypes, card types and subtypes of each
+/// face of a card.
+///
+/// Type lines are parsed without talking to the API. Any word after the dash
+/// is taken as a subtype, to check them against the subtypes Scryfall knows
+/// about use [`TypeLine::parse_validated`].
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::{CardType, Supertype, TypeLine};
+///
+/// let type_line: TypeLine = "Legendary Creature — Human Wizard".parse().unwrap();
+/// assert!(type_line.has_supertype(Supertype::Legendary));
+/// assert!(type_line.has_type(CardType::Creature));
+/// assert!(type_line.has_subtype("Wizard"));
+///
+/// let fire_ice: TypeLine = "Instant // Instant".parse().unwrap();
+/// assert_eq!(fire_ice.faces().len(), 2);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TypeLine {
+    faces: Vec<FaceTypes>,
+}
+
+impl TypeLine {
+    /// Parses a type line, checking every subtype against the catalogs of
+    /// subtypes of the card types of its face.
+    ///
+    /// Subtypes of card types that have no catalog in `subtypes`, such as
+    /// planes, aren't checked.
+    pub fn parse_validated(
+        type_line: &str,
+        subtypes: &SubtypeCatalog,
+    ) -> Result<Self, ParseTypeLineError> {
+        let parsed = Self::parse(type_line, &|phrase| subtypes.contains(phrase))?;
+        for face in &parsed.faces {
+            for subtype in &face.subtypes {
+                if !face.types.iter().any(|t| subtypes.allows(t, subtype)) {
+                    return Err(ParseTypeLineError::new(
+                        type_line,
+                        format!("unknown subtype {:?}", subtype),
+                    ));
+                }
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn parse(
+        type_line: &str,
+        is_subtype: &dyn Fn(&str) -> bool,
+    ) -> Result<Self, ParseTypeLineError> {
+        type_line
+            .split("//")
+            .map(|face| FaceTypes::parse(face, is_subtype))
+            .collect::<Result<_, _>>()
+            .map(|faces| TypeLine { faces })
+            .map_err(|reason| ParseTypeLineError::new(type_line, reason.to_string()))
+    }
+
+    /// The types of each face, in the order they appear on the type line.
+    pub fn faces(&self) -> &[FaceTypes] {
+        &self.faces
+    }
+
+    /// Returns true if any face has the given card type.
+    pub fn has_type(&self, card_type: CardType) -> bool {
+        self.faces
+            .iter()
+            .any(|face| face.types.contains(&card_type))
+    }
+
+    /// Returns true if any face has the given supertype.
+    pub fn has_supertype(&self, supertype: Supertype) -> bool {
+        self.faces.iter().any(|face| face.has_supertype(supertype))
+    }
+
+    /// Returns true if any face has the given subtype. The comparison ignores
+    /// case.
+    pub fn has_subtype(&self, subtype: &str) -> bool {
+        self.faces.iter().any(|face| face.has_subtype(subtype))
+    }
+}
+
+impl FromStr for TypeLine {
+    type Err = ParseTypeLineError;
+
+    /// Parses a type line such as `Legendary Creature — Human Wizard`. Faces
+    /// are separated by `//`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TypeLine::parse(s, &|phrase| MULTI_WORD_SUBTYPES.contains(&phrase))
+    }
+}
+
+impl fmt::Display for TypeLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, face) in self.faces.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" // ")?;
+            }
+            fmt::Display::fmt(face, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for TypeLine {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TypeLine {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// The subtypes each card type may have, used to validate type lines with
+/// [`TypeLine::parse_validated`].
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::{SubtypeCatalog, TypeLine};
+/// # tokio_test::block_on(async {
+/// let subtypes = SubtypeCatalog::fetch().await.unwrap();
+/// assert!(TypeLine::parse_validated("Creature — Time Lord Doctor", &subtypes).is_ok());
+/// assert!(TypeLine::parse_validated("Creature — Equipment", &subtypes).is_err());
+/// # })
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct SubtypeCatalog {
+    subtypes: HashMap<CardType, HashSet<String>>,
+}
+
+impl SubtypeCatalog {
+    /// Fetches the catalogs of creature, planeswalker, land, artifact,
+    /// enchantment, spell and battle types.
+    pub async fn fetch() -> crate::Result<Self> {
+        Ok(SubtypeCatalog::default()
+            .with(CardType::Creature, &Catalog::creature_types().await?)
+            .with(
+                CardType::Planeswalker,
+                &Catalog::planeswalker_types().await?,
+            )
+            .with(CardType::Land, &Catalog::land_types().await?)
+            .with(CardType::Artifact, &Catalog::artifact_types().await?)
+            .with(CardType::Enchantment, &Catalog::enchantment_types().await?)
+            .with(CardType::Sorcery, &Catalog::spell_types().await?)
+            .with(CardType::Battle, &Catalog::battle_types().await?))
+    }
+
+    /// Adds the subtypes in `catalog` to the ones `card_type` may have.
+    pub fn with(mut self, card_type: CardType, catalog: &Catalog) -> Self {
+        self.subtypes
+            .entry(card_type.subtype_kind())
+            .or_default()
+            .extend(catalog.data.iter().cloned());
+        self
+    }
+
+    /// Returns true if a card of type `card_type` may have `subtype`, or if
+    /// there's no catalog for the subtypes of `card_type`.
+    pub fn allows(&self, card_type: &CardType, subtype: &str) -> bool {
+        self.subtypes
+            .get(&card_type.subtype_kind())
+            .is_none_or(|subtypes| subtypes.contains(subtype))
+    }
+
+    fn contains(&self, subtype: &str) -> bool {
+        self.subtypes
+            .values()
+            .any(|subtypes| subtypes.contains(subtype))
+    }
+}
+
+/// The error returned when a type line can't be parsed, or has a subtype that
+/// isn't allowed.
+#[derive(Clone, Eq, PartialEq, Debug, thiserror::Error)]
+#[error("invalid type line {type_line:?}: {reason}")]
+pub struct ParseTypeLineError {
+    type_line: String,
+    reason: String,
+}
+
+impl ParseTypeLineError {
+    fn new(type_line: &str, reason: String) -> Self {
+        ParseTypeLineError {
+            type_line: type_line.to_string(),
+            reason,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::util::test_card::card;
+
+    fn catalog(data: &[&str]) -> Catalog {
+        serde_json::from_value(json!({
+            "uri": "https://api.scryfall.com/catalog/creature-types",
+            "total_values": data.len(),
+            "data": data,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_each_face() {
+        let type_line: TypeLine = "Legendary Snow Creature — Elf Druid // Land"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            type_line.faces(),
+            [
+                FaceTypes {
+                    supertypes: vec![Supertype::Legendary, Supertype::Snow],
+                    types: vec![CardType::Creature],
+                    subtypes: vec!["Elf".to_string(), "Druid".to_string()],
+                },
+                FaceTypes {
+                    supertypes: vec![],
+                    types: vec![CardType::Land],
+                    subtypes: vec![],
+                },
+            ]
+        );
+        assert_eq!(
+            type_line.to_string(),
+            "Legendary Snow Creature — Elf Druid // Land"
+        );
+
+        let doctor: TypeLine = "Legendary Creature — Time Lord Doctor".parse().unwrap();
+        assert_eq!(doctor.faces()[0].subtypes, ["Time Lord", "Doctor"]);
+    }
+
+    #[test]
+    fn rejects_malformed_type_lines() {
+        for type_line in ["", "Creature —", "Instant // "] {
+            assert!(type_line.parse::<TypeLine>().is_err(), "{:?}", type_line);
+        }
+    }
+
+    #[test]
+    fn parses_unknown_card_types_with_unknown_variants() {
+        let type_line = "Legendary Goblin".parse::<TypeLine>();
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "unknown_variants")] {
+                let type_line = type_line.unwrap();
+                assert_eq!(type_line.faces()[0].types, [CardType::Unknown("Goblin".into())]);
+                assert_eq!(type_line.to_string(), "Legendary Goblin");
+            } else if #[cfg(feature = "unknown_variants_slim")] {
+                assert_eq!(type_line.unwrap().faces()[0].types, [CardType::Unknown]);
+            } else {
+                assert!(type_line.is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn validates_subtypes_against_catalogs() {
+        let subtypes = SubtypeCatalog::default()
+            .with(
+                CardType::Creature,
+                &catalog(&["Goblin", "Shaman", "Time Lord"]),
+            )
+            .with(CardType::Sorcery, &catalog(&["Arcane", "Lesson"]))
+            .with(CardType::Artifact, &catalog(&["Equipment"]));
+        let valid = [
+            "Creature — Goblin Shaman",
+            "Kindred Instant — Goblin",
+            "Instant — Arcane",
+            "Artifact Creature — Goblin",
+            "Creature — Time Lord",
+            "Plane — Dominaria",
+        ];
+        for type_line in valid {
+            assert!(
+                TypeLine::parse_validated(type_line, &subtypes).is_ok(),
+                "{}",
+                type_line
+            );
+        }
+        for type_line in ["Creature — Equipment", "Instant — Goblin"] {
+            assert!(TypeLine::parse_validated(type_line, &subtypes).is_err());
+        }
+    }
+
+    #[test]
+    fn answers_card_predicates() {
+        let goblin = card(json!({ "type_line": "Legendary Creature — Goblin" }));
+        assert!(goblin.is_creature() && goblin.is_legendary() && !goblin.is_land());
+        assert!(goblin.has_subtype("goblin"));
+
+        let bolt = card(json!({}));
+        assert!(!bolt.is_creature() && bolt.has_type(CardType::Instant));
+
+        let reversible = card(json!({
+            "type_line": null,
+            "card_faces": [
+                {
+                    "object": "card_face",
+                    "name": "Bolt",
+                    "mana_cost": "{R}",
+                    "type_line": "Instant",
+                },
+                {
+                    "object": "card_face",
+                    "name": "Goblin",
+                    "mana_cost": "{R}",
+                    "type_line": "Creature — Goblin",
+                },
+            ],
+        }));
+        let type_line = reversible.parse_type_line().unwrap().unwrap();
+        assert_eq!(type_line.to_string(), "Instant // Creature — Goblin");
+        assert!(reversible.is_creature());
+    }
+}
@@ -15,7 +15,6 @@ use crate::util::CATALOG_URL;
 /// Magic software and understanding possible values for a field on Card
 /// objects.
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct Catalog {
     /// A link to the current catalog on Scryfall’s API.
     pub uri: Uri<Catalog>,
@@ -25,6 +24,15 @@ pub struct Catalog {
 
     /// An array of datapoints, as strings.
     pub data: Vec<String>,
+
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<Catalog, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
 }
 
 impl Catalog {
@@ -165,6 +173,20 @@ impl Catalog {
         Uri::from(CATALOG_URL.join("spell-types")?).fetch().await
     }
 
+    /// Returns a Catalog of all battle types in Scryfall’s database. Values are
+    /// updated as soon as a new card is entered for spoiler seasons.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::catalog::Catalog;
+    /// # tokio_test::block_on(async {
+    /// assert!(Catalog::battle_types().await.unwrap().data.len() > 0)
+    /// # })
+    /// ```
+    pub async fn battle_types() -> crate::Result<Self> {
+        Uri::from(CATALOG_URL.join("battle-types")?).fetch().await
+    }
+
     /// Returns a Catalog of all possible values for a creature or vehicle’s
     /// power in Scryfall’s database. Values are updated as soon as a new
     /// card is entered for spoiler seasons.
@@ -290,4 +312,153 @@ impl Catalog {
     pub async fn ability_words() -> crate::Result<Self> {
         Uri::from(CATALOG_URL.join("ability-words")?).fetch().await
     }
+
+    /// Returns up to 20 of the names in this catalog that could be
+    /// autocompletions of `query`, like [`Card::autocomplete`] does, but
+    /// without making a request. This is meant to be used with a stored copy
+    /// of [`Catalog::card_names`].
+    ///
+    /// Capitalization, spaces and punctuation are ignored. Names that start
+    /// with the query come first, followed by names with a word that starts
+    /// with it, names that contain it and finally names that contain its
+    /// letters in order. Queries shorter than 2 letters have no suggestions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::catalog::Catalog;
+    /// # tokio_test::block_on(async {
+    /// let names = Catalog::card_names().await.unwrap();
+    /// let suggestions = names.autocomplete("thal");
+    /// assert!(suggestions.data.iter().any(|name| name == "Thalia, Guardian of Thraben"));
+    /// # })
+    /// ```
+    ///
+    /// [`Card::autocomplete`]: crate::Card::autocomplete
+    pub fn autocomplete(&self, query: &str) -> Catalog {
+        const MAX_SUGGESTIONS: usize = 20;
+
+        let query = normalize(query);
+        let mut suggestions = Vec::new();
+        if query.chars().count() >= 2 {
+            suggestions = self
+                .data
+                .iter()
+                .filter_map(|name| Some((match_rank(name, &query)?, name)))
+                .collect::<Vec<_>>();
+            // The sort is stable, so names keep the catalog's order within a
+            // rank.
+            suggestions.sort_by_key(|(rank, _)| *rank);
+            suggestions.truncate(MAX_SUGGESTIONS);
+        }
+        Catalog {
+            uri: self.uri.clone(),
+            total_values: suggestions.len(),
+            data: suggestions
+                .into_iter()
+                .map(|(_, name)| name.clone())
+                .collect(),
+            #[cfg(feature = "extra_fields")]
+            extra: self.extra.clone(),
+        }
+    }
+}
+
+/// Lowercases `text` and drops everything that isn't a letter or a digit.
+fn normalize(text: &str) -> String {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+/// How well `name` matches the normalized `query`, lower being better, or None
+/// if it doesn't match at all.
+fn match_rank(name: &str, query: &str) -> Option<u8> {
+    let normalized = normalize(name);
+    if normalized.starts_with(query) {
+        return Some(0);
+    }
+    let mut previous = ' ';
+    let word_starts_with_query = name.char_indices().any(|(i, c)| {
+        let word_start = !previous.is_alphanumeric() && c.is_alphanumeric();
+        previous = c;
+        word_start && normalize(&name[i..]).starts_with(query)
+    });
+    if word_starts_with_query {
+        return Some(1);
+    }
+    if normalized.contains(query) {
+        return Some(2);
+    }
+    let mut letters = normalized.chars();
+    if query.chars().all(|q| letters.any(|c| c == q)) {
+        return Some(3);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn catalog(names: &[&str]) -> Catalog {
+        Catalog {
+            uri: Uri::try_from("https://api.scryfall.com/catalog/card-names").unwrap(),
+            total_values: names.len(),
+            data: names.iter().map(|name| name.to_string()).collect(),
+            #[cfg(feature = "extra_fields")]
+            extra: Default::default(),
+        }
+    }
+
+    #[test]
+    fn autocomplete_ranks_prefixes_first() {
+        let names = catalog(&[
+            "Blazing Bolt",
+            "Bolt of Keranos",
+            "Boltwing Marauder",
+            "Lightning Bolt",
+            "Thunderbolt",
+        ]);
+        assert_eq!(
+            names.autocomplete("bolt").data,
+            [
+                "Bolt of Keranos",
+                "Boltwing Marauder",
+                "Blazing Bolt",
+                "Lightning Bolt",
+                "Thunderbolt",
+            ]
+        );
+        assert_eq!(names.autocomplete("bolt").total_values, 5);
+    }
+
+    #[test]
+    fn autocomplete_ignores_case_and_punctuation() {
+        let names = catalog(&["Jace, the Mind Sculptor", "Nissa, Who Shakes the World"]);
+        assert_eq!(
+            names.autocomplete("JACE THE").data,
+            ["Jace, the Mind Sculptor"]
+        );
+        assert_eq!(
+            names.autocomplete("who-shakes").data,
+            ["Nissa, Who Shakes the World"]
+        );
+        assert_eq!(names.autocomplete("jtms").data, ["Jace, the Mind Sculptor"]);
+    }
+
+    #[test]
+    fn autocomplete_needs_two_letters() {
+        let names = catalog(&["Opt"]);
+        assert!(names.autocomplete("o").data.is_empty());
+        assert!(names.autocomplete(" ,").data.is_empty());
+        assert_eq!(names.autocomplete("op").data, ["Opt"]);
+    }
+
+    #[test]
+    fn autocomplete_returns_at_most_20_names() {
+        let names = (0..30).map(|i| format!("Card {i}")).collect::<Vec<_>>();
+        let names = catalog(&names.iter().map(String::as_str).collect::<Vec<_>>());
+        assert_eq!(names.autocomplete("card").data.len(), 20);
+    }
 }
@@ -0,0 +1,816 @@
+//! This module defines [`ScryfallClient`], the handle through which every
+//! request to the Scryfall API is made.
+//!
+//! All the functions in this crate, such as [`Card::named`] or
+//! [`Set::code`], use the *current* client. Unless told otherwise this is a
+//! default client, which talks to `https://api.scryfall.com/` and identifies
+//! itself with a `scryfall-rs` user agent.
+//!
+//! A custom client can be built with [`ScryfallClient::builder`] and then
+//! either installed as the default for the whole program with
+//! [`ScryfallClient::set_default`], or used for a single piece of work with
+//! [`ScryfallClient::scope`].
+//!
+//! Requests made through the same client are spaced out according to its
+//! [`RateLimiter`], following Scryfall's request for clients to wait 50 to 100
+//! milliseconds between requests. Requests that fail for transient reasons,
+//! such as `429 Too Many Requests` or `503 Service Unavailable`, are retried
+//! according to its [`RetryPolicy`]. A client can also be given a
+//! [`ResponseCache`], so that looking up the same resources over and over
+//! doesn't cost a request each time.
+//!
+//! The requests themselves are sent by a [`Transport`], which is backed by
+//! `reqwest` by default but can be replaced. See the [`transport`] module.
+//! A [`Cassette`] is a transport that records requests and their responses to
+//! a file and replays them later, for tests that run without network access.
+//!
+//! To learn about changes to Scryfall's API before they break anything, a
+//! client can be given a [`DriftRecorder`], which reports the fields and enum
+//! values in its responses that this crate doesn't know about.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use scryfall::{Card, ScryfallClient};
+//! # tokio_test::block_on(async {
+//! let client = ScryfallClient::builder()
+//!     .user_agent("my-bot/1.0")
+//!     .timeout(Duration::from_secs(10))
+//!     .build()
+//!     .unwrap();
+//!
+//! let bolt = client.scope(Card::named("Lightning Bolt")).await.unwrap();
+//! assert_eq!(bolt.name, "Lightning Bolt");
+//! # })
+//! ```
+//!
+//! [`Card::named`]: crate::Card::named
+//! [`Set::code`]: crate::Set::code
+mod cache;
+mod cassette;
+mod rate_limit;
+mod response;
+mod retry;
+pub(crate) mod schema_drift;
+pub mod transport;
+
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use http::header::{self, HeaderMap, HeaderName, HeaderValue};
+use http::Method;
+use httpstatus::StatusCode;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use url::Url;
+
+use self::cache::CacheEntry;
+pub use self::cache::{ResourceKind, ResponseCache};
+pub use self::cassette::Cassette;
+pub use self::rate_limit::RateLimiter;
+pub(crate) use self::response::Response;
+pub use self::retry::RetryPolicy;
+pub use self::schema_drift::{DriftRecorder, DriftReport};
+use self::transport::Transport;
+use crate::error::{is_retryable_status, Error};
+use crate::util::trace::RequestTrace;
+use crate::util::ROOT_URL;
+
+tokio::task_local! {
+    static CURRENT: ScryfallClient;
+}
+
+static DEFAULT: Lazy<RwLock<Option<ScryfallClient>>> = Lazy::new(|| RwLock::new(None));
+
+/// A configured connection to the Scryfall API.
+///
+/// Cloning a `ScryfallClient` is cheap, all clones share the same underlying
+/// connection pool and configuration.
+///
+/// See the [module documentation](self) for how the client is picked up by the
+/// rest of the crate.
+#[derive(Clone)]
+pub struct ScryfallClient {
+    inner: Arc<ClientInner>,
+}
+
+struct ClientInner {
+    transport: Arc<dyn Transport>,
+    base_url: Url,
+    headers: HeaderMap,
+    timeout: Option<Duration>,
+    rate_limiter: RateLimiter,
+    retry_policy: RetryPolicy,
+    cache: Option<ResponseCache>,
+    schema_drift: Option<DriftRecorder>,
+}
+
+impl fmt::Debug for ScryfallClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScryfallClient")
+            .field("transport", &self.inner.transport)
+            .field("base_url", &self.inner.base_url.as_str())
+            .field("headers", &self.inner.headers)
+            .field("timeout", &self.inner.timeout)
+            .field("rate_limiter", &self.inner.rate_limiter)
+            .field("retry_policy", &self.inner.retry_policy)
+            .field("cache", &self.inner.cache)
+            .field("schema_drift", &self.inner.schema_drift)
+            .finish()
+    }
+}
+
+impl Default for ScryfallClient {
+    fn default() -> Self {
+        ScryfallClient::builder()
+            .build()
+            .expect("the default client configuration is valid")
+    }
+}
+
+impl ScryfallClient {
+    /// Creates a [`ScryfallClientBuilder`] with the default configuration.
+    pub fn builder() -> ScryfallClientBuilder {
+        ScryfallClientBuilder::new()
+    }
+
+    /// Returns the client used by requests made from the current task.
+    ///
+    /// This is the client passed to the innermost enclosing
+    /// [`scope`](Self::scope) call, or the program wide default client if
+    /// there is none.
+    pub fn current() -> ScryfallClient {
+        CURRENT
+            .try_with(ScryfallClient::clone)
+            .unwrap_or_else(|_| ScryfallClient::global())
+    }
+
+    /// Replaces the program wide default client. Every request made outside of
+    /// a [`scope`](Self::scope) will use `client` from now on.
+    pub fn set_default(client: ScryfallClient) {
+        *DEFAULT.write().unwrap_or_else(|e| e.into_inner()) = Some(client);
+    }
+
+    #[cfg(not(test))]
+    fn global() -> ScryfallClient {
+        if let Some(client) = &*DEFAULT.read().unwrap_or_else(|e| e.into_inner()) {
+            return client.clone();
+        }
+        DEFAULT
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_or_insert_with(ScryfallClient::default)
+            .clone()
+    }
+
+    // Each test runs in its own tokio runtime, and a connection pool can't
+    // outlive the runtime it was created in, so tests get a fresh client
+    // unless one was explicitly installed.
+    #[cfg(test)]
+    fn global() -> ScryfallClient {
+        DEFAULT
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .unwrap_or_default()
+    }
+
+    /// Runs `future` with this client as the [current](Self::current) client.
+    ///
+    /// Every request made by the crate while the future is being polled goes
+    /// through this client, including the pages requested later by any
+    /// [`ListIter`](crate::list::ListIter) created inside of it.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use scryfall::{Set, ScryfallClient};
+    /// # tokio_test::block_on(async {
+    /// let client = ScryfallClient::builder()
+    ///     .user_agent("my-app/0.1")
+    ///     .build()
+    ///     .unwrap();
+    /// let mmq = client.scope(Set::code("mmq")).await.unwrap();
+    /// assert_eq!(mmq.name, "Mercadian Masques");
+    /// # })
+    /// ```
+    pub async fn scope<F: Future>(&self, future: F) -> F::Output {
+        CURRENT.scope(self.clone(), future).await
+    }
+
+    /// The url all API requests are made against.
+    pub fn base_url(&self) -> &Url {
+        &self.inner.base_url
+    }
+
+    /// The limiter spacing out the requests made through this client.
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.inner.rate_limiter
+    }
+
+    /// The policy for retrying requests made through this client.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.inner.retry_policy
+    }
+
+    /// The cache responses to requests made through this client are stored
+    /// in, if there is one.
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.inner.cache.as_ref()
+    }
+
+    /// The recorder of the unknown parts of this client's responses, if there
+    /// is one.
+    pub fn schema_drift(&self) -> Option<&DriftRecorder> {
+        self.inner.schema_drift.as_ref()
+    }
+
+    /// Maps a url pointing to the official API onto this client's base url.
+    /// Urls pointing anywhere else, such as bulk data downloads, are left
+    /// untouched.
+    pub(crate) fn resolve(&self, url: &Url) -> crate::Result<Url> {
+        match url.as_str().strip_prefix(ROOT_URL.as_str()) {
+            Some(path) if self.inner.base_url != *ROOT_URL => Ok(self.inner.base_url.join(path)?),
+            _ => Ok(url.clone()),
+        }
+    }
+
+    /// Sends a GET request for `url`, turning error statuses into an
+    /// [`Error`]. Responses are served from and stored in the client's
+    /// [`ResponseCache`], if it has one.
+    pub(crate) async fn get(&self, url: &Url) -> crate::Result<Response> {
+        let url = self.resolve(url)?;
+        let trace = RequestTrace::new(&Method::GET, &url);
+        let Some((cache, kind)) = self.inner.cache.as_ref().and_then(|cache| {
+            ResponseCache::kind(&self.inner.base_url, &url).map(|kind| (cache, kind))
+        }) else {
+            return self.send(self.request(Method::GET, url), &trace).await;
+        };
+
+        let cached = cache.get(&url).await;
+        let conditional_headers = match &cached {
+            Some(entry) if entry.is_fresh(cache.ttl(kind)) => {
+                trace.cache("hit");
+                trace.body_read(entry.body.len());
+                return Ok(Response::from_bytes(
+                    200,
+                    entry.headers(),
+                    entry.body.clone(),
+                ));
+            },
+            Some(entry) => entry.conditional_headers(),
+            None => HeaderMap::new(),
+        };
+
+        let mut request = self.request(Method::GET, url.clone());
+        request.headers.extend(conditional_headers);
+        let response = self.send(request, &trace).await?;
+        let entry = match (response.status(), cached) {
+            (304, Some(entry)) => {
+                trace.cache("revalidated");
+                entry.revalidated()
+            },
+            (200, _) => {
+                trace.cache("miss");
+                let headers = response.headers().clone();
+                CacheEntry::new(&headers, response.bytes().await?)
+            },
+            _ => return Ok(response),
+        };
+        cache.insert(&url, entry.clone()).await;
+        Ok(Response::from_bytes(200, entry.headers(), entry.body))
+    }
+
+    /// Sends a POST request for `url` with `body` serialized as json, turning
+    /// error statuses into an [`Error`]. These requests are never retried, and
+    /// their responses are never cached.
+    pub(crate) async fn post_json<B: Serialize + ?Sized>(
+        &self,
+        url: &Url,
+        body: &B,
+    ) -> crate::Result<Response> {
+        let url = self.resolve(url)?;
+        let trace = RequestTrace::new(&Method::POST, &url);
+        let mut request = self.request(Method::POST, url);
+        request.headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        request.body = Some(serde_json::to_vec(body)?.into());
+        self.send(request, &trace).await
+    }
+
+    /// Creates a request with the client's headers and timeout.
+    fn request(&self, method: Method, url: Url) -> transport::Request {
+        let mut request = transport::Request::new(method, url);
+        request.headers = self.inner.headers.clone();
+        request.timeout = self.inner.timeout;
+        request
+    }
+
+    /// Sends `request`, turning error statuses into an [`Error`]. Transient
+    /// failures of `GET` requests are retried according to the client's
+    /// [`RetryPolicy`]; other requests are only attempted once.
+    async fn send(
+        &self,
+        request: transport::Request,
+        trace: &RequestTrace,
+    ) -> crate::Result<Response> {
+        let policy = &self.inner.retry_policy;
+        let mut attempt = 1;
+        loop {
+            self.inner.rate_limiter.acquire().await;
+            let last_attempt = attempt >= policy.attempts() || request.method != Method::GET;
+            let delay = match trace
+                .in_scope(self.inner.transport.send(request.clone()))
+                .await
+            {
+                Ok(response) if (400..=599).contains(&response.status) => {
+                    let status = response.status;
+                    if last_attempt || !is_retryable_status(status) {
+                        trace.finished(Some(status), attempt);
+                        return Err(error_from_response(response, trace).await);
+                    }
+                    let delay = policy.backoff(attempt, Some(&response.headers));
+                    trace.retrying(attempt, delay, &format_args!("status {status}"));
+                    delay
+                },
+                Ok(response) => {
+                    trace.finished(Some(response.status), attempt);
+                    return Ok(Response::from_network(response, trace.clone()));
+                },
+                Err(e) => {
+                    let error = Error::TransportError {
+                        error: Box::new(e),
+                        url: request.url.clone(),
+                    };
+                    if last_attempt || !error.is_retryable() {
+                        trace.finished(None, attempt);
+                        return Err(error);
+                    }
+                    let delay = policy.backoff(attempt, None);
+                    trace.retrying(attempt, delay, &error);
+                    delay
+                },
+            };
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Builds the error for a response with an error status. Scryfall describes the
+/// problem in the body, but a proxy in front of it might not.
+async fn error_from_response(response: transport::Response, trace: &RequestTrace) -> Error {
+    let status = response.status;
+    match Response::from_network(response, trace.clone())
+        .bytes()
+        .await
+    {
+        Ok(body) => match serde_json::from_slice(&body) {
+            Ok(error) => Error::ScryfallError(error),
+            Err(_) => Error::HttpError(StatusCode::from(status)),
+        },
+        Err(e) => e,
+    }
+}
+
+/// A builder for a [`ScryfallClient`].
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+///
+/// use scryfall::ScryfallClient;
+///
+/// let client = ScryfallClient::builder()
+///     .base_url("http://localhost:8080/".parse().unwrap())
+///     .user_agent("my-tool/2.3")
+///     .timeout(Duration::from_secs(30))
+///     .build()
+///     .unwrap();
+/// assert_eq!(client.base_url().as_str(), "http://localhost:8080/");
+/// ```
+#[derive(Debug)]
+pub struct ScryfallClientBuilder {
+    base_url: Url,
+    user_agent: String,
+    headers: Vec<(String, String)>,
+    timeout: Option<Duration>,
+    #[cfg(feature = "reqwest")]
+    connect_timeout: Option<Duration>,
+    transport: Option<Arc<dyn Transport>>,
+    rate_limiter: RateLimiter,
+    retry_policy: RetryPolicy,
+    cache: Option<ResponseCache>,
+    schema_drift: Option<DriftRecorder>,
+}
+
+impl Default for ScryfallClientBuilder {
+    fn default() -> Self {
+        ScryfallClientBuilder::new()
+    }
+}
+
+impl ScryfallClientBuilder {
+    /// Constructs a builder with the default configuration.
+    pub fn new() -> Self {
+        ScryfallClientBuilder {
+            base_url: ROOT_URL.clone(),
+            user_agent: "scryfall-rs".to_string(),
+            headers: Vec::new(),
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            connect_timeout: None,
+            transport: None,
+            rate_limiter: RateLimiter::default(),
+            retry_policy: RetryPolicy::default(),
+            cache: None,
+            schema_drift: None,
+        }
+    }
+
+    /// Sets the url the API requests are made against, for example a local
+    /// stand-in for Scryfall. Defaults to `https://api.scryfall.com/`.
+    pub fn base_url(&mut self, base_url: Url) -> &mut Self {
+        self.base_url = base_url;
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request. Defaults to
+    /// `scryfall-rs`.
+    pub fn user_agent(&mut self, user_agent: impl Into<String>) -> &mut Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Adds a header that will be sent with every request.
+    pub fn header(&mut self, name: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the timeout for a whole request, from connecting until the body has
+    /// been read.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing a connection. This has no effect if a
+    /// transport was provided with [`http_client`](Self::http_client) or
+    /// [`transport`](Self::transport).
+    #[cfg(feature = "reqwest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reqwest")))]
+    pub fn connect_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Uses an existing [`reqwest::Client`] instead of creating a new one. This
+    /// allows configuring things like proxies and TLS.
+    #[cfg(feature = "reqwest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reqwest")))]
+    pub fn http_client(&mut self, client: reqwest::Client) -> &mut Self {
+        self.transport(transport::ReqwestTransport::from(client))
+    }
+
+    /// Sends requests through `transport` instead of the default
+    /// [`ReqwestTransport`](transport::ReqwestTransport). See the
+    /// [`transport`] module.
+    pub fn transport(&mut self, transport: impl Transport + 'static) -> &mut Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Sets the limiter used to space out requests. Defaults to waiting 100
+    /// milliseconds between requests. Use [`RateLimiter::disabled`] to turn
+    /// rate limiting off.
+    pub fn rate_limiter(&mut self, rate_limiter: RateLimiter) -> &mut Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Sets the policy for retrying requests that failed for transient
+    /// reasons. Use [`RetryPolicy::never`] to turn retrying off.
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Stores the responses to requests made through the client in `cache`,
+    /// and serves repeated requests from it. No responses are cached by
+    /// default.
+    pub fn cache(&mut self, cache: ResponseCache) -> &mut Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Records the fields and enum values in the responses to requests made
+    /// through the client that this crate doesn't know about in `recorder`.
+    /// Nothing is recorded by default.
+    pub fn schema_drift(&mut self, recorder: DriftRecorder) -> &mut Self {
+        self.schema_drift = Some(recorder);
+        self
+    }
+
+    /// Creates the client.
+    ///
+    /// Fails if any of the configured headers is invalid.
+    pub fn build(&self) -> crate::Result<ScryfallClient> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_static("*/*"));
+        headers.insert(header::USER_AGENT, header_value(&self.user_agent)?);
+        for (name, value) in &self.headers {
+            let name = HeaderName::try_from(name.as_str())
+                .map_err(|e| Error::Other(format!("invalid header name {name:?}: {e}")))?;
+            headers.append(name, header_value(value)?);
+        }
+
+        let transport = match &self.transport {
+            Some(transport) => transport.clone(),
+            None => self.default_transport()?,
+        };
+
+        let mut base_url = self.base_url.clone();
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+
+        Ok(ScryfallClient {
+            inner: Arc::new(ClientInner {
+                transport,
+                base_url,
+                headers,
+                timeout: self.timeout,
+                rate_limiter: self.rate_limiter.clone(),
+                retry_policy: self.retry_policy.clone(),
+                cache: self.cache.clone(),
+                schema_drift: self.schema_drift.clone(),
+            }),
+        })
+    }
+
+    #[cfg(feature = "reqwest")]
+    fn default_transport(&self) -> crate::Result<Arc<dyn Transport>> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        let client = builder
+            .build()
+            .map_err(|e| Error::Other(format!("failed to build http client: {e}")))?;
+        Ok(Arc::new(transport::ReqwestTransport::from(client)))
+    }
+
+    #[cfg(not(feature = "reqwest"))]
+    fn default_transport(&self) -> crate::Result<Arc<dyn Transport>> {
+        Ok(Arc::new(transport::NoTransport))
+    }
+}
+
+fn header_value(value: &str) -> crate::Result<HeaderValue> {
+    HeaderValue::from_str(value)
+        .map_err(|e| Error::Other(format!("invalid header value {value:?}: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_server::TestServer;
+    use crate::Catalog;
+
+    #[test]
+    fn resolves_api_urls_against_base_url() {
+        let client = ScryfallClient::builder()
+            .base_url("http://localhost:1234/scryfall".parse().unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(
+            client
+                .resolve(&ROOT_URL.join("cards/named?exact=Opt").unwrap())
+                .unwrap()
+                .as_str(),
+            "http://localhost:1234/scryfall/cards/named?exact=Opt"
+        );
+
+        let download = Url::parse("https://data.scryfall.io/rulings/rulings.json").unwrap();
+        assert_eq!(client.resolve(&download).unwrap(), download);
+    }
+
+    #[test]
+    fn rejects_invalid_headers() {
+        assert!(ScryfallClient::builder()
+            .user_agent("bad\nagent")
+            .build()
+            .is_err());
+        assert!(ScryfallClient::builder()
+            .header("bad header", "value")
+            .build()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn scoped_client_is_used() {
+        let server = TestServer::start(vec![(
+            200,
+            r#"{"uri":"https://api.scryfall.com/catalog/powers","total_values":1,"data":["*"]}"#,
+        )])
+        .await;
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .user_agent("scryfall-rs-tests")
+            .header("x-test", "yes")
+            .build()
+            .unwrap();
+
+        let catalog = client.scope(Catalog::powers()).await.unwrap();
+        assert_eq!(catalog.data, ["*"]);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /catalog/powers HTTP/1.1"));
+        assert!(requests[0].contains("user-agent: scryfall-rs-tests"));
+        assert!(requests[0].contains("x-test: yes"));
+    }
+
+    const POWERS: &str =
+        r#"{"uri":"https://api.scryfall.com/catalog/powers","total_values":1,"data":["*"]}"#;
+    const UNAVAILABLE: &str =
+        r#"{"object":"error","status":503,"code":"unavailable","details":"Try again later."}"#;
+
+    fn retrying_client(server: &TestServer, max_attempts: u32) -> ScryfallClient {
+        ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .retry_policy(
+                RetryPolicy::default()
+                    .max_attempts(max_attempts)
+                    .initial_backoff(Duration::from_millis(1)),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors() {
+        let server = TestServer::start(vec![(503, UNAVAILABLE), (200, POWERS)]).await;
+        let client = retrying_client(&server, 3);
+
+        let catalog = client.scope(Catalog::powers()).await.unwrap();
+        assert_eq!(catalog.data, ["*"]);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn retries_too_many_requests_after_delay() {
+        let server = TestServer::start_with_headers(vec![
+            (429, vec![("retry-after", "0")], ""),
+            (200, Vec::new(), POWERS),
+        ])
+        .await;
+        let client = retrying_client(&server, 2);
+
+        client.scope(Catalog::powers()).await.unwrap();
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors() {
+        let server = TestServer::start(vec![(
+            404,
+            r#"{"object":"error","status":404,"code":"not_found","details":"No such catalog."}"#,
+        )])
+        .await;
+        let client = retrying_client(&server, 3);
+
+        let error = client.scope(Catalog::powers()).await.unwrap_err();
+        assert_eq!(error.status(), Some(404));
+        assert!(!error.is_retryable());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let server =
+            TestServer::start(vec![(503, UNAVAILABLE), (502, "<html>Bad Gateway</html>")]).await;
+        let client = retrying_client(&server, 2);
+
+        let error = client.scope(Catalog::powers()).await.unwrap_err();
+        assert!(matches!(error, Error::HttpError(_)));
+        assert_eq!(error.status(), Some(502));
+        assert!(error.is_retryable());
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_posts() {
+        let server = TestServer::start(vec![(503, UNAVAILABLE)]).await;
+        let client = retrying_client(&server, 3);
+
+        let error = client
+            .scope(crate::Card::collection(vec![
+                crate::card::CardIdentifier::MtgoId(33926),
+            ]))
+            .await
+            .unwrap_err();
+        assert_eq!(error.status(), Some(503));
+        assert_eq!(server.requests().len(), 1);
+        assert!(server.requests()[0].starts_with("POST /cards/collection HTTP/1.1"));
+    }
+
+    #[tokio::test]
+    async fn serves_fresh_responses_from_cache() {
+        let server = TestServer::start(vec![(200, POWERS)]).await;
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .cache(ResponseCache::new(10))
+            .build()
+            .unwrap();
+
+        for _ in 0..3 {
+            let catalog = client.scope(Catalog::powers()).await.unwrap();
+            assert_eq!(catalog.data, ["*"]);
+        }
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn revalidates_stale_responses() {
+        let server = TestServer::start_with_headers(vec![
+            (200, vec![("etag", "\"v1\"")], POWERS),
+            (304, vec![("etag", "\"v1\"")], ""),
+        ])
+        .await;
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .cache(ResponseCache::new(10).with_ttl(ResourceKind::Catalog, Duration::ZERO))
+            .build()
+            .unwrap();
+
+        client.scope(Catalog::powers()).await.unwrap();
+        let catalog = client.scope(Catalog::powers()).await.unwrap();
+        assert_eq!(catalog.data, ["*"]);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(!requests[0].contains("if-none-match"));
+        assert!(requests[1].contains("if-none-match: \"v1\""));
+    }
+
+    #[derive(Debug, Default)]
+    struct FakeTransport {
+        requests: Arc<std::sync::Mutex<Vec<transport::Request>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for FakeTransport {
+        async fn send(
+            &self,
+            request: transport::Request,
+        ) -> Result<transport::Response, transport::TransportError> {
+            self.requests.lock().unwrap().push(request);
+            let body = futures::stream::iter([
+                Ok(bytes::Bytes::from_static(
+                    br#"{"uri":"https://api.scryfall.com/catalog/powers","#,
+                )),
+                Ok(bytes::Bytes::from_static(
+                    br#""total_values":1,"data":["*"]}"#,
+                )),
+            ]);
+            Ok(transport::Response::new(
+                200,
+                HeaderMap::new(),
+                futures::StreamExt::boxed(body),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn requests_go_through_the_transport() {
+        let fake = FakeTransport::default();
+        let requests = fake.requests.clone();
+        let client = ScryfallClient::builder()
+            .transport(fake)
+            .timeout(Duration::from_secs(5))
+            .rate_limiter(RateLimiter::disabled())
+            .build()
+            .unwrap();
+
+        let catalog = client.scope(Catalog::powers()).await.unwrap();
+        assert_eq!(catalog.data, ["*"]);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::GET);
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://api.scryfall.com/catalog/powers"
+        );
+        assert_eq!(requests[0].headers[header::USER_AGENT], "scryfall-rs");
+        assert_eq!(requests[0].timeout, Some(Duration::from_secs(5)));
+    }
+}
@@ -0,0 +1,501 @@
+//! Caching of API responses.
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bytes::Bytes;
+use http::header::{self, HeaderMap, HeaderValue};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// The kinds of resources served by the Scryfall API, each of which can be
+/// cached for a different amount of time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResourceKind {
+    /// Single cards, such as the ones returned by
+    /// [`Card::named`](crate::Card::named).
+    Card,
+    /// Card searches and autocompletion.
+    Search,
+    /// Sets.
+    Set,
+    /// Catalogs.
+    Catalog,
+    /// Rulings.
+    Ruling,
+    /// Bulk data files. Only the descriptions are cached, never the files
+    /// themselves.
+    BulkData,
+    /// Card symbols.
+    Symbology,
+    /// Anything else.
+    Other,
+}
+
+impl ResourceKind {
+    /// Classifies a request by the path of its url, relative to the client's
+    /// base url. Returns `None` for requests that must never be cached.
+    fn of(path: &str) -> Option<ResourceKind> {
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let mut segments = path.split('/').filter(|s| !s.is_empty());
+        let kind = match segments.next()? {
+            "cards" => match segments.next() {
+                Some("random") => return None,
+                Some("search" | "autocomplete") => ResourceKind::Search,
+                _ if path.trim_end_matches('/').ends_with("/rulings") => ResourceKind::Ruling,
+                _ => ResourceKind::Card,
+            },
+            "sets" => ResourceKind::Set,
+            "catalog" => ResourceKind::Catalog,
+            "bulk-data" => ResourceKind::BulkData,
+            "symbology" => ResourceKind::Symbology,
+            _ => ResourceKind::Other,
+        };
+        Some(kind)
+    }
+
+    /// How long responses of this kind are used without asking Scryfall
+    /// whether they changed.
+    ///
+    /// Scryfall updates its card data about once a day, so cards, sets and
+    /// the like are kept for 24 hours. Everything else is kept for an hour.
+    fn default_ttl(self) -> Duration {
+        const HOUR: Duration = Duration::from_secs(60 * 60);
+        match self {
+            ResourceKind::Card
+            | ResourceKind::Set
+            | ResourceKind::Catalog
+            | ResourceKind::Ruling
+            | ResourceKind::Symbology => 24 * HOUR,
+            ResourceKind::Search | ResourceKind::BulkData | ResourceKind::Other => HOUR,
+        }
+    }
+}
+
+/// A cache for responses from the Scryfall API.
+///
+/// Successful responses are kept in memory, in a least recently used cache of
+/// a fixed number of entries, and optionally in a directory on disk, where
+/// they survive restarts of the program.
+///
+/// While an entry is younger than the time to live of its [`ResourceKind`] it
+/// is used without contacting Scryfall at all. Once it's older, the next
+/// request asks Scryfall whether the resource changed, using the `ETag` and
+/// `Last-Modified` headers of the cached response. If it didn't, the cached
+/// copy is used and kept for another time to live.
+///
+/// Only `GET` requests to the API are cached. Random cards and bulk data
+/// downloads never are.
+///
+/// A `ResponseCache` is cheap to clone and all clones share the same entries.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+///
+/// use scryfall::client::{ResourceKind, ResponseCache};
+/// use scryfall::ScryfallClient;
+///
+/// let cache = ResponseCache::new(1000)
+///     .with_ttl(ResourceKind::Search, Duration::from_secs(5 * 60))
+///     .with_disk_store(std::env::temp_dir().join("scryfall-cache"));
+/// let client = ScryfallClient::builder().cache(cache).build().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct ResponseCache {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    capacity: usize,
+    config: Mutex<Config>,
+    memory: Mutex<Lru>,
+}
+
+/// The settings that can be changed after the cache is created.
+#[derive(Default)]
+struct Config {
+    ttls: HashMap<ResourceKind, Duration>,
+    disk: Option<PathBuf>,
+}
+
+impl fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let config = self.config();
+        f.debug_struct("ResponseCache")
+            .field("capacity", &self.inner.capacity)
+            .field("ttls", &config.ttls)
+            .field("disk", &config.disk)
+            .finish()
+    }
+}
+
+impl ResponseCache {
+    /// Creates a cache that keeps up to `capacity` responses in memory.
+    pub fn new(capacity: usize) -> Self {
+        ResponseCache {
+            inner: Arc::new(Inner {
+                capacity,
+                config: Mutex::new(Config::default()),
+                memory: Mutex::new(Lru::default()),
+            }),
+        }
+    }
+
+    /// Sets how long responses of `kind` are used before being revalidated.
+    /// A time to live of zero revalidates every response before using it.
+    ///
+    /// The setting is shared with the clones of this cache, and applies to the
+    /// responses already cached.
+    pub fn with_ttl(self, kind: ResourceKind, ttl: Duration) -> Self {
+        self.config().ttls.insert(kind, ttl);
+        self
+    }
+
+    /// Also stores responses as files in `dir`, which is created if it doesn't
+    /// exist. Entries evicted from memory are then still found on disk.
+    ///
+    /// The setting is shared with the clones of this cache. Responses already
+    /// cached in memory are kept, but aren't written to `dir`.
+    pub fn with_disk_store(self, dir: impl Into<PathBuf>) -> Self {
+        self.config().disk = Some(dir.into());
+        self
+    }
+
+    /// The time to live of responses of `kind`.
+    pub fn ttl(&self, kind: ResourceKind) -> Duration {
+        self.config()
+            .ttls
+            .get(&kind)
+            .copied()
+            .unwrap_or_else(|| kind.default_ttl())
+    }
+
+    /// Removes every entry, both from memory and from disk.
+    pub async fn clear(&self) -> std::io::Result<()> {
+        self.memory().clear();
+        if let Some(dir) = self.disk() {
+            match tokio::fs::remove_dir_all(dir).await {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+                _ => {},
+            }
+        }
+        Ok(())
+    }
+
+    fn memory(&self) -> std::sync::MutexGuard<'_, Lru> {
+        self.inner.memory.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn config(&self) -> std::sync::MutexGuard<'_, Config> {
+        self.inner.config.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn disk(&self) -> Option<PathBuf> {
+        self.config().disk.clone()
+    }
+
+    /// Classifies `url`, returning `None` if it must not be cached.
+    pub(crate) fn kind(base_url: &Url, url: &Url) -> Option<ResourceKind> {
+        let path = url.as_str().strip_prefix(base_url.as_str())?;
+        ResourceKind::of(path)
+    }
+
+    pub(crate) async fn get(&self, url: &Url) -> Option<CacheEntry> {
+        if let Some(entry) = self.memory().get(url.as_str()) {
+            return Some(entry);
+        }
+        let entry = read_from_disk(&self.disk()?, url).await?;
+        self.memory()
+            .insert(url.to_string(), entry.clone(), self.inner.capacity);
+        Some(entry)
+    }
+
+    pub(crate) async fn insert(&self, url: &Url, entry: CacheEntry) {
+        self.memory()
+            .insert(url.to_string(), entry.clone(), self.inner.capacity);
+        if let Some(dir) = self.disk() {
+            // The cache is only an optimization, failing to write to it must
+            // not fail the request.
+            let _ = write_to_disk(&dir, url, &entry).await;
+        }
+    }
+}
+
+/// A cached response.
+#[derive(Clone, Debug)]
+pub(crate) struct CacheEntry {
+    pub(crate) body: Bytes,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    stored_at: SystemTime,
+}
+
+impl CacheEntry {
+    pub(crate) fn new(headers: &HeaderMap, body: Bytes) -> Self {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|v: &HeaderValue| v.to_str().ok())
+                .map(String::from)
+        };
+        CacheEntry {
+            body,
+            etag: header(header::ETAG),
+            last_modified: header(header::LAST_MODIFIED),
+            stored_at: SystemTime::now(),
+        }
+    }
+
+    pub(crate) fn is_fresh(&self, ttl: Duration) -> bool {
+        self.stored_at
+            .elapsed()
+            .map_or(true, |elapsed| elapsed < ttl)
+    }
+
+    /// The same entry, stored again now after Scryfall confirmed it's still
+    /// up to date.
+    pub(crate) fn revalidated(self) -> Self {
+        CacheEntry {
+            stored_at: SystemTime::now(),
+            ..self
+        }
+    }
+
+    /// The headers that ask Scryfall to only send the resource if it changed
+    /// since this entry was stored.
+    pub(crate) fn conditional_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        let etag = self.etag.as_deref().and_then(|v| v.parse().ok());
+        if let Some(etag) = etag {
+            headers.insert(header::IF_NONE_MATCH, etag);
+        }
+        let last_modified = self.last_modified.as_deref().and_then(|v| v.parse().ok());
+        if let Some(last_modified) = last_modified {
+            headers.insert(header::IF_MODIFIED_SINCE, last_modified);
+        }
+        headers
+    }
+
+    /// The headers to serve this entry with.
+    pub(crate) fn headers(&self) -> HeaderMap {
+        let mut headers = self.conditional_headers();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        headers
+    }
+}
+
+/// A map that forgets its least recently used entries once full.
+#[derive(Default)]
+struct Lru {
+    entries: HashMap<String, (CacheEntry, u64)>,
+    /// The keys of `entries`, by the tick they were last used at.
+    by_use: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Lru {
+    fn get(&mut self, key: &str) -> Option<CacheEntry> {
+        let (entry, used) = self.entries.get_mut(key)?;
+        self.tick += 1;
+        let key = self.by_use.remove(used).unwrap_or_default();
+        *used = self.tick;
+        self.by_use.insert(self.tick, key);
+        Some(entry.clone())
+    }
+
+    fn insert(&mut self, key: String, entry: CacheEntry, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+        self.tick += 1;
+        if let Some((_, used)) = self.entries.insert(key.clone(), (entry, self.tick)) {
+            self.by_use.remove(&used);
+        }
+        self.by_use.insert(self.tick, key);
+        while self.entries.len() > capacity {
+            let Some((_, oldest)) = self.by_use.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.by_use.clear();
+    }
+}
+
+/// How an entry is stored on disk.
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// Seconds since the unix epoch.
+    stored_at: u64,
+    body: String,
+}
+
+/// The file an entry for `url` is stored in. Urls can be far longer than file
+/// names are allowed to be, so they are hashed, with FNV-1a because it's
+/// stable across Rust versions.
+fn disk_path(dir: &Path, url: &Url) -> PathBuf {
+    let hash = url
+        .as_str()
+        .bytes()
+        .fold(0xcbf29ce484222325_u64, |hash, b| {
+            (hash ^ u64::from(b)).wrapping_mul(0x100000001b3)
+        });
+    dir.join(format!("{hash:016x}.json"))
+}
+
+async fn read_from_disk(dir: &Path, url: &Url) -> Option<CacheEntry> {
+    let file = tokio::fs::read(disk_path(dir, url)).await.ok()?;
+    let entry: DiskEntry = serde_json::from_slice(&file).ok()?;
+    // Another url could hash to the same file.
+    if entry.url != url.as_str() {
+        return None;
+    }
+    Some(CacheEntry {
+        body: Bytes::from(entry.body),
+        etag: entry.etag,
+        last_modified: entry.last_modified,
+        stored_at: UNIX_EPOCH + Duration::from_secs(entry.stored_at),
+    })
+}
+
+async fn write_to_disk(dir: &Path, url: &Url, entry: &CacheEntry) -> std::io::Result<()> {
+    let body = std::str::from_utf8(&entry.body)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    let file = serde_json::to_vec(&DiskEntry {
+        url: url.to_string(),
+        etag: entry.etag.clone(),
+        last_modified: entry.last_modified.clone(),
+        stored_at: entry
+            .stored_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        body: body.to_string(),
+    })?;
+    tokio::fs::create_dir_all(dir).await?;
+    // Write to a temporary file first so a concurrent reader never sees a
+    // partially written entry.
+    let path = disk_path(dir, url);
+    let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+    tokio::fs::write(&tmp, file).await?;
+    tokio::fs::rename(tmp, path).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(body: &'static str) -> CacheEntry {
+        CacheEntry::new(&HeaderMap::new(), Bytes::from_static(body.as_bytes()))
+    }
+
+    #[test]
+    fn classifies_resources() {
+        let kind = |path| ResourceKind::of(path);
+        assert_eq!(kind("cards/named?exact=Opt"), Some(ResourceKind::Card));
+        assert_eq!(kind("cards/mmq/1"), Some(ResourceKind::Card));
+        assert_eq!(kind("cards/search?q=zurgo"), Some(ResourceKind::Search));
+        assert_eq!(
+            kind("cards/autocomplete?q=thal"),
+            Some(ResourceKind::Search)
+        );
+        assert_eq!(kind("cards/mmq/1/rulings"), Some(ResourceKind::Ruling));
+        assert_eq!(kind("cards/random?q=t:goblin"), None);
+        assert_eq!(kind("sets/mmq"), Some(ResourceKind::Set));
+        assert_eq!(kind("catalog/powers"), Some(ResourceKind::Catalog));
+        assert_eq!(kind("bulk-data/oracle_cards"), Some(ResourceKind::BulkData));
+        assert_eq!(kind("symbology"), Some(ResourceKind::Symbology));
+        assert_eq!(kind("migrations"), Some(ResourceKind::Other));
+    }
+
+    #[test]
+    fn only_api_urls_are_cached() {
+        let base = Url::parse("https://api.scryfall.com/").unwrap();
+        let url = |s| Url::parse(s).unwrap();
+        assert_eq!(
+            ResponseCache::kind(&base, &url("https://api.scryfall.com/sets/mmq")),
+            Some(ResourceKind::Set)
+        );
+        assert_eq!(
+            ResponseCache::kind(&base, &url("https://data.scryfall.io/all-cards.json")),
+            None
+        );
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut lru = Lru::default();
+        lru.insert("a".into(), entry("a"), 2);
+        lru.insert("b".into(), entry("b"), 2);
+        assert!(lru.get("a").is_some());
+        lru.insert("c".into(), entry("c"), 2);
+        assert!(lru.get("b").is_none());
+        assert!(lru.get("a").is_some());
+        assert!(lru.get("c").is_some());
+        assert_eq!(lru.entries.len(), lru.by_use.len());
+    }
+
+    #[test]
+    fn zero_ttl_is_never_fresh() {
+        let entry = entry("{}");
+        assert!(!entry.is_fresh(Duration::ZERO));
+        assert!(entry.is_fresh(Duration::from_secs(60)));
+    }
+
+    #[tokio::test]
+    async fn settings_are_shared_and_keep_cached_responses() {
+        let url = Url::parse("https://api.scryfall.com/sets/mmq").unwrap();
+        let cache = ResponseCache::new(10);
+        let clone = cache.clone();
+        cache
+            .insert(
+                &url,
+                CacheEntry::new(&HeaderMap::new(), Bytes::from_static(b"{}")),
+            )
+            .await;
+
+        let cache = cache.with_ttl(ResourceKind::Set, Duration::ZERO);
+        assert_eq!(clone.ttl(ResourceKind::Set), Duration::ZERO);
+        assert!(cache.get(&url).await.is_some());
+        assert!(clone.get(&url).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn disk_store_survives_new_cache() {
+        let dir = std::env::temp_dir().join(format!("scryfall-cache-test-{}", std::process::id()));
+        let url = Url::parse("https://api.scryfall.com/sets/mmq").unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ETAG, HeaderValue::from_static("\"v1\""));
+
+        let cache = ResponseCache::new(10).with_disk_store(&dir);
+        cache
+            .insert(&url, CacheEntry::new(&headers, Bytes::from_static(b"{}")))
+            .await;
+
+        let reopened = ResponseCache::new(10).with_disk_store(&dir);
+        let entry = reopened.get(&url).await.unwrap();
+        assert_eq!(entry.body, "{}");
+        assert_eq!(entry.conditional_headers()[header::IF_NONE_MATCH], "\"v1\"");
+
+        reopened.clear().await.unwrap();
+        assert!(ResponseCache::new(10)
+            .with_disk_store(&dir)
+            .get(&url)
+            .await
+            .is_none());
+    }
+}
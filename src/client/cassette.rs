@@ -0,0 +1,370 @@
+//! Recording and replaying of HTTP interactions.
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use http::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+use super::transport::{Request, Response, Transport, TransportError};
+
+/// A [`Transport`] that records the requests made through it, along with their
+/// responses, to a file, or that replays a previously recorded file without
+/// touching the network.
+///
+/// This allows tests that talk to Scryfall to run offline and
+/// deterministically: they are run once against the real API to record a
+/// cassette, which is then checked in and replayed on every following run.
+///
+/// When replaying, each request is answered with the response recorded for the
+/// same method, url and body. Identical requests get their recorded responses
+/// in the order they were recorded. A request that wasn't recorded fails with
+/// an error naming it, rather than silently reaching the network.
+///
+/// Whole response bodies are held in memory while recording, and they must
+/// be valid UTF-8, so cassettes aren't suited to bulk data downloads.
+///
+/// # Examples
+/// ```rust,no_run
+/// use scryfall::client::Cassette;
+/// use scryfall::{Card, ScryfallClient};
+///
+/// # tokio_test::block_on(async {
+/// // Records to the file if it doesn't exist yet, replays it otherwise.
+/// let cassette = Cassette::auto("tests/cassettes/bolt.json").unwrap();
+/// let client = ScryfallClient::builder().transport(cassette).build().unwrap();
+/// let bolt = client.scope(Card::named("Lightning Bolt")).await.unwrap();
+/// assert_eq!(bolt.name, "Lightning Bolt");
+/// # })
+/// ```
+pub struct Cassette {
+    path: PathBuf,
+    mode: Mode,
+}
+
+enum Mode {
+    Record {
+        transport: Box<dyn Transport>,
+        tape: Mutex<Tape>,
+    },
+    Replay {
+        responses: Mutex<HashMap<Key, VecDeque<RecordedResponse>>>,
+    },
+}
+
+impl fmt::Debug for Cassette {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode = match self.mode {
+            Mode::Record { .. } => "record",
+            Mode::Replay { .. } => "replay",
+        };
+        f.debug_struct("Cassette")
+            .field("path", &self.path)
+            .field("mode", &mode)
+            .finish()
+    }
+}
+
+impl Cassette {
+    /// Sends requests through `transport`, recording every interaction to the
+    /// file at `path`. The file is overwritten, and kept up to date as requests
+    /// are made.
+    pub fn record(path: impl Into<PathBuf>, transport: impl Transport + 'static) -> Self {
+        Cassette {
+            path: path.into(),
+            mode: Mode::Record {
+                transport: Box::new(transport),
+                tape: Mutex::new(Tape::default()),
+            },
+        }
+    }
+
+    /// Answers requests with the interactions recorded in the file at `path`.
+    ///
+    /// Fails if the file can't be read or isn't a cassette.
+    pub fn replay(path: impl Into<PathBuf>) -> crate::Result<Self> {
+        let path = path.into();
+        let tape: Tape = serde_json::from_slice(&std::fs::read(&path)?)?;
+        let mut responses = HashMap::<_, VecDeque<_>>::new();
+        for interaction in tape.interactions {
+            responses
+                .entry(interaction.request)
+                .or_default()
+                .push_back(interaction.response);
+        }
+        Ok(Cassette {
+            path,
+            mode: Mode::Replay {
+                responses: Mutex::new(responses),
+            },
+        })
+    }
+
+    /// Replays the file at `path` if it exists, and records to it through a
+    /// [`ReqwestTransport`](super::transport::ReqwestTransport) otherwise.
+    ///
+    /// Setting the `SCRYFALL_CASSETTE` environment variable to `record` or
+    /// `replay` forces one mode, for example to record a cassette again after
+    /// the API changed.
+    #[cfg(feature = "reqwest")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reqwest")))]
+    pub fn auto(path: impl Into<PathBuf>) -> crate::Result<Self> {
+        let path = path.into();
+        let record = match std::env::var("SCRYFALL_CASSETTE").as_deref() {
+            Ok("record") => true,
+            Ok("replay") => false,
+            Ok(other) => {
+                return Err(crate::Error::Other(format!(
+                    "SCRYFALL_CASSETTE must be `record` or `replay`, not {other:?}"
+                )))
+            },
+            Err(_) => !path.exists(),
+        };
+        if record {
+            Ok(Cassette::record(
+                path,
+                super::transport::ReqwestTransport::new(),
+            ))
+        } else {
+            Cassette::replay(path)
+        }
+    }
+
+    /// The file this cassette is recorded to or replayed from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns true if this cassette replays recorded responses instead of
+    /// making requests.
+    pub fn is_replaying(&self) -> bool {
+        matches!(self.mode, Mode::Replay { .. })
+    }
+
+    fn error(&self, message: impl fmt::Display) -> TransportError {
+        TransportError::other(format!("cassette {}: {message}", self.path.display()))
+    }
+}
+
+#[async_trait]
+impl Transport for Cassette {
+    async fn send(&self, request: Request) -> Result<Response, TransportError> {
+        let key = Key::of(&request).map_err(|e| self.error(e))?;
+        match &self.mode {
+            Mode::Replay { responses } => {
+                let response = responses
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .get_mut(&key)
+                    .and_then(VecDeque::pop_front)
+                    .ok_or_else(|| {
+                        self.error(format_args!(
+                            "no recorded response left for {} {}",
+                            key.method, key.url
+                        ))
+                    })?;
+                Ok(response.into_response())
+            },
+            Mode::Record { transport, tape } => {
+                let response = transport.send(request).await?;
+                let body = response
+                    .body
+                    .try_fold(BytesMut::new(), |mut body, chunk| async move {
+                        body.extend_from_slice(&chunk);
+                        Ok(body)
+                    })
+                    .await
+                    .map_err(TransportError::other)?
+                    .freeze();
+                let recorded = RecordedResponse {
+                    status: response.status,
+                    headers: response
+                        .headers
+                        .iter()
+                        .filter_map(|(name, value)| {
+                            Some((name.to_string(), value.to_str().ok()?.to_string()))
+                        })
+                        .collect(),
+                    body: String::from_utf8(body.to_vec()).map_err(|e| {
+                        self.error(format_args!(
+                            "can't record the body of {} {}: {e}",
+                            key.method, key.url
+                        ))
+                    })?,
+                };
+
+                let file = {
+                    let mut tape = tape.lock().unwrap_or_else(|e| e.into_inner());
+                    tape.interactions.push(Interaction {
+                        request: key,
+                        response: recorded,
+                    });
+                    serde_json::to_vec_pretty(&*tape).map_err(|e| self.error(e))?
+                };
+                if let Some(dir) = self.path.parent() {
+                    tokio::fs::create_dir_all(dir)
+                        .await
+                        .map_err(|e| self.error(e))?;
+                }
+                tokio::fs::write(&self.path, file)
+                    .await
+                    .map_err(|e| self.error(e))?;
+
+                Ok(Response::new(
+                    response.status,
+                    response.headers,
+                    stream::once(async { Ok(body) }).boxed(),
+                ))
+            },
+        }
+    }
+}
+
+/// The contents of a cassette file.
+#[derive(Default, Serialize, Deserialize)]
+struct Tape {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Interaction {
+    request: Key,
+    response: RecordedResponse,
+}
+
+/// What a request is matched on.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct Key {
+    method: String,
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+}
+
+impl Key {
+    fn of(request: &Request) -> Result<Self, std::str::Utf8Error> {
+        Ok(Key {
+            method: request.method.to_string(),
+            url: request.url.to_string(),
+            body: request
+                .body
+                .as_deref()
+                .map(std::str::from_utf8)
+                .transpose()?
+                .map(String::from),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl RecordedResponse {
+    fn into_response(self) -> Response {
+        let headers = self
+            .headers
+            .iter()
+            .filter_map(|(name, value)| {
+                Some((
+                    HeaderName::try_from(name.as_str()).ok()?,
+                    HeaderValue::try_from(value.as_str()).ok()?,
+                ))
+            })
+            .collect::<HeaderMap>();
+        let body = Bytes::from(self.body);
+        Response::new(
+            self.status,
+            headers,
+            stream::once(async { Ok(body) }).boxed(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::Method;
+    use url::Url;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Canned;
+
+    #[async_trait]
+    impl Transport for Canned {
+        async fn send(&self, request: Request) -> Result<Response, TransportError> {
+            let body = Bytes::from(format!(r#"{{"url":"{}"}}"#, request.url));
+            let mut headers = HeaderMap::new();
+            headers.insert("etag", HeaderValue::from_static("\"v1\""));
+            Ok(Response::new(
+                200,
+                headers,
+                stream::once(async { Ok(body) }).boxed(),
+            ))
+        }
+    }
+
+    async fn body(response: Response) -> String {
+        let chunks = response.body.try_collect::<Vec<_>>().await.unwrap();
+        String::from_utf8(chunks.concat()).unwrap()
+    }
+
+    fn get(url: &str) -> Request {
+        Request::new(Method::GET, Url::parse(url).unwrap())
+    }
+
+    #[tokio::test]
+    async fn replays_what_was_recorded() {
+        let path = std::env::temp_dir().join(format!(
+            "scryfall-cassette-test-{}.json",
+            std::process::id()
+        ));
+
+        let recorder = Cassette::record(&path, Canned);
+        assert!(!recorder.is_replaying());
+        let recorded = recorder
+            .send(get("https://api.scryfall.com/sets/mmq"))
+            .await
+            .unwrap();
+        assert_eq!(
+            body(recorded).await,
+            r#"{"url":"https://api.scryfall.com/sets/mmq"}"#
+        );
+
+        let player = Cassette::replay(&path).unwrap();
+        assert!(player.is_replaying());
+        let replayed = player
+            .send(get("https://api.scryfall.com/sets/mmq"))
+            .await
+            .unwrap();
+        assert_eq!(replayed.status, 200);
+        assert_eq!(replayed.headers["etag"], "\"v1\"");
+        assert_eq!(
+            body(replayed).await,
+            r#"{"url":"https://api.scryfall.com/sets/mmq"}"#
+        );
+
+        // Each recorded response is only played once.
+        let error = player
+            .send(get("https://api.scryfall.com/sets/mmq"))
+            .await
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("no recorded response left for GET https://api.scryfall.com/sets/mmq"));
+        assert!(player
+            .send(get("https://api.scryfall.com/sets/war"))
+            .await
+            .is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,201 @@
+//! Spacing of requests made to the Scryfall API.
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// Limits how often requests can be sent to Scryfall.
+///
+/// Scryfall [asks](https://scryfall.com/docs/api#rate-limits-and-good-citizenship)
+/// that clients insert 50 to 100 milliseconds of delay between requests, and
+/// will answer with `429 Too Many Requests` to clients that don't. By default
+/// every [`ScryfallClient`](crate::ScryfallClient) waits at least 100
+/// milliseconds between requests.
+///
+/// A `RateLimiter` is cheap to clone and all clones share the same schedule,
+/// so the same limiter can be given to several clients to keep their combined
+/// traffic within the limit.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+///
+/// use scryfall::client::RateLimiter;
+/// use scryfall::ScryfallClient;
+///
+/// // At most 10 requests per second, with short bursts of up to 3 requests.
+/// let limiter = RateLimiter::per_second(10).with_burst(3);
+/// let client = ScryfallClient::builder()
+///     .rate_limiter(limiter)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct RateLimiter {
+    inner: Option<Arc<Inner>>,
+}
+
+struct Inner {
+    interval: Duration,
+    burst: u32,
+    /// The time at which the next request would be sent if requests were
+    /// perfectly spaced out.
+    next: Mutex<Option<Instant>>,
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.inner {
+            Some(inner) => f
+                .debug_struct("RateLimiter")
+                .field("interval", &inner.interval)
+                .field("burst", &inner.burst)
+                .finish(),
+            None => f.write_str("RateLimiter(disabled)"),
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        RateLimiter::new(Duration::from_millis(100))
+    }
+}
+
+impl RateLimiter {
+    /// Creates a limiter that waits at least `interval` between two requests.
+    pub fn new(interval: Duration) -> Self {
+        if interval.is_zero() {
+            return RateLimiter::disabled();
+        }
+        RateLimiter {
+            inner: Some(Arc::new(Inner {
+                interval,
+                burst: 1,
+                next: Mutex::new(None),
+            })),
+        }
+    }
+
+    /// Creates a limiter that allows at most `requests` requests per second.
+    pub fn per_second(requests: u32) -> Self {
+        match requests {
+            0 => RateLimiter::disabled(),
+            n => RateLimiter::new(Duration::from_secs(1) / n),
+        }
+    }
+
+    /// Creates a limiter that never delays requests.
+    pub fn disabled() -> Self {
+        RateLimiter { inner: None }
+    }
+
+    /// Allows up to `burst` requests to be sent back to back after a period of
+    /// inactivity. The average rate stays the same.
+    pub fn with_burst(self, burst: u32) -> Self {
+        match self.inner {
+            Some(inner) => RateLimiter {
+                inner: Some(Arc::new(Inner {
+                    interval: inner.interval,
+                    burst: burst.max(1),
+                    next: Mutex::new(None),
+                })),
+            },
+            None => self,
+        }
+    }
+
+    /// Returns true if this limiter never delays requests.
+    pub fn is_disabled(&self) -> bool {
+        self.inner.is_none()
+    }
+
+    /// Waits until another request may be sent.
+    pub(crate) async fn acquire(&self) {
+        let Some(inner) = &self.inner else {
+            return;
+        };
+        let now = Instant::now();
+        let send_at = {
+            let mut next = inner.next.lock().unwrap_or_else(|e| e.into_inner());
+            let scheduled = next.map_or(now, |next| next.max(now));
+            *next = Some(scheduled + inner.interval);
+            // Up to `burst` requests may be sent ahead of their schedule.
+            inner
+                .interval
+                .checked_mul(inner.burst - 1)
+                .and_then(|ahead| scheduled.checked_sub(ahead))
+                .unwrap_or(now)
+        };
+        if send_at > now {
+            tokio::time::sleep_until(send_at).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn spaces_requests() {
+        let limiter = RateLimiter::new(Duration::from_millis(20));
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() >= Duration::from_millis(80));
+    }
+
+    #[tokio::test]
+    async fn clones_share_the_schedule() {
+        let limiter = RateLimiter::new(Duration::from_millis(20));
+        let other = limiter.clone();
+        let start = Instant::now();
+        futures::join!(
+            async {
+                for _ in 0..3 {
+                    limiter.acquire().await;
+                }
+            },
+            async {
+                for _ in 0..3 {
+                    other.acquire().await;
+                }
+            },
+        );
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn allows_bursts() {
+        let limiter = RateLimiter::new(Duration::from_secs(10)).with_burst(3);
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn allows_huge_bursts() {
+        let limiter = RateLimiter::new(Duration::from_secs(1 << 40)).with_burst(u32::MAX);
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn disabled_never_waits() {
+        let limiter = RateLimiter::per_second(0);
+        assert!(limiter.is_disabled());
+        let start = Instant::now();
+        for _ in 0..100 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,88 @@
+//! The responses handed back by [`ScryfallClient`](super::ScryfallClient).
+use std::io;
+
+use bytes::{Bytes, BytesMut};
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use http::HeaderMap;
+use serde::de::DeserializeOwned;
+
+use super::schema_drift::{self, DriftRecorder};
+use super::transport;
+use crate::util::trace::RequestTrace;
+
+/// A response to a request, either still being received from the network or
+/// served from the [`ResponseCache`](super::ResponseCache).
+pub(crate) struct Response {
+    status: u16,
+    headers: HeaderMap,
+    body: Body,
+}
+
+enum Body {
+    Full(Bytes),
+    Network(transport::Body, RequestTrace),
+}
+
+impl Response {
+    pub(crate) fn from_network(response: transport::Response, trace: RequestTrace) -> Self {
+        Response {
+            status: response.status,
+            headers: response.headers,
+            body: Body::Network(response.body, trace),
+        }
+    }
+
+    pub(crate) fn from_bytes(status: u16, headers: HeaderMap, body: Bytes) -> Self {
+        Response {
+            status,
+            headers,
+            body: Body::Full(body),
+        }
+    }
+
+    pub(crate) fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub(crate) fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Reads the whole body.
+    pub(crate) async fn bytes(self) -> crate::Result<Bytes> {
+        match self.body {
+            Body::Full(bytes) => Ok(bytes),
+            Body::Network(body, trace) => {
+                let bytes = body
+                    .try_fold(BytesMut::new(), |mut bytes, chunk| async move {
+                        bytes.extend_from_slice(&chunk);
+                        Ok(bytes)
+                    })
+                    .await?;
+                trace.body_read(bytes.len());
+                Ok(bytes.freeze())
+            },
+        }
+    }
+
+    /// Reads the whole body and deserializes it as json, adding what it
+    /// doesn't know about to `drift`, if given.
+    pub(crate) async fn json<T: DeserializeOwned>(
+        self,
+        drift: Option<&DriftRecorder>,
+    ) -> crate::Result<T> {
+        let body = self.bytes().await?;
+        let mut deserializer = serde_json::Deserializer::from_slice(&body);
+        let value = schema_drift::deserialize(&mut deserializer, drift)?;
+        deserializer.end()?;
+        Ok(value)
+    }
+
+    /// The body as a stream of chunks, as they are received.
+    pub(crate) fn bytes_stream(self) -> BoxStream<'static, io::Result<Bytes>> {
+        match self.body {
+            Body::Full(bytes) => stream::once(async { Ok(bytes) }).boxed(),
+            Body::Network(body, trace) => trace.count_body(body),
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Retrying of requests that failed for transient reasons.
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use http::header::{HeaderMap, RETRY_AFTER};
+
+/// Describes how requests that failed with a transient error are retried.
+///
+/// A request is retried when Scryfall answers with `429 Too Many Requests` or
+/// a `5xx` status, or when the connection fails or times out. See
+/// [`Error::is_retryable`](crate::Error::is_retryable).
+///
+/// Between attempts the client waits for an exponentially growing delay, with
+/// some random jitter so that many clients don't retry in lockstep. If the
+/// response carries a `Retry-After` header, that delay is used instead.
+///
+/// Only idempotent `GET` requests are retried.
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+///
+/// use scryfall::client::RetryPolicy;
+/// use scryfall::ScryfallClient;
+///
+/// let client = ScryfallClient::builder()
+///     .retry_policy(
+///         RetryPolicy::default()
+///             .max_attempts(5)
+///             .initial_backoff(Duration::from_secs(1)),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    /// Up to 3 attempts, waiting 500 milliseconds before the first retry and
+    /// at most 30 seconds between attempts.
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn never() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the maximum number of times a request is attempted, including the
+    /// first attempt. A value of 1 disables retrying.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry. Each following retry waits twice
+    /// as long as the previous one.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Sets the longest the client will wait between two attempts, including
+    /// delays requested through `Retry-After`.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Sets whether the delays are randomly shortened by up to half, so that
+    /// clients that failed at the same time don't retry at the same time.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// The maximum number of attempts for a request.
+    pub fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// How long to wait before making attempt number `attempt + 1`, given the
+    /// headers of the failed response, if there was one.
+    pub(crate) fn backoff(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+        if let Some(delay) = headers.and_then(retry_after) {
+            return delay.min(self.max_backoff);
+        }
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff);
+        if self.jitter {
+            exponential.mul_f64(0.5 + random_fraction() / 2.0)
+        } else {
+            exponential
+        }
+    }
+}
+
+/// Parses a `Retry-After` header, which holds either a number of seconds or a
+/// date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// A random number in `[0, 1)`, good enough for jitter.
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(Utc::now().timestamp_nanos_opt().unwrap_or_default() as u64);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn exponential_backoff() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(5))
+            .jitter(false);
+        let delays = (1..=5)
+            .map(|attempt| policy.backoff(attempt, None).as_secs())
+            .collect::<Vec<_>>();
+        assert_eq!(delays, [1, 2, 4, 5, 5]);
+    }
+
+    #[test]
+    fn jitter_shortens_delay_by_at_most_half() {
+        let policy = RetryPolicy::default().initial_backoff(Duration::from_secs(8));
+        for _ in 0..100 {
+            let delay = policy.backoff(1, None);
+            assert!(delay >= Duration::from_secs(4) && delay <= Duration::from_secs(8));
+        }
+    }
+
+    #[test]
+    fn honors_retry_after() {
+        let policy = RetryPolicy::default().max_backoff(Duration::from_secs(60));
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(policy.backoff(1, Some(&headers)), Duration::from_secs(7));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+        assert_eq!(policy.backoff(1, Some(&headers)), Duration::from_secs(60));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(policy.backoff(1, Some(&headers)), Duration::ZERO);
+    }
+}
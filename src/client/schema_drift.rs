@@ -0,0 +1,471 @@
+//! Reporting of the parts of Scryfall's responses this crate doesn't know.
+use std::any::type_name;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_ignored::Path;
+
+/// Collects a [`DriftReport`] of the fields and enum values Scryfall sends
+/// that this crate doesn't know about, so that API changes can be noticed
+/// before they start breaking deserialization.
+///
+/// Give a recorder to a client with
+/// [`ScryfallClientBuilder::schema_drift`](crate::client::ScryfallClientBuilder::schema_drift),
+/// and every object deserialized from the client's responses is checked,
+/// including the items of [bulk data](crate::bulk) streams and the pages of
+/// [lists](crate::list::ListIter).
+///
+/// What can be reported depends on the enabled features:
+///
+/// - Unknown fields are always reported. With the `extra_fields` feature they
+///   are named after the object that keeps them, like `Price.usd_glossy`,
+///   instead of by their path from the object that was deserialized, like
+///   `Card.prices.usd_glossy`.
+/// - Unknown values of enums like [`PromoType`](crate::card::PromoType) or
+///   [`SetType`](crate::set::SetType) are only reported with the
+///   `unknown_variants` feature. Without it they fail to deserialize, and with
+///   `unknown_variants_slim` their value is lost.
+/// - Unknown formats in a card's [legalities](crate::card::CardLegality) are
+///   always reported.
+///
+/// A `DriftRecorder` is cheap to clone and all clones add to the same report.
+///
+/// # Examples
+/// ```rust,no_run
+/// use scryfall::client::DriftRecorder;
+/// use scryfall::{Card, ScryfallClient};
+/// # tokio_test::block_on(async {
+/// let drift = DriftRecorder::new();
+/// let client = ScryfallClient::builder()
+///     .schema_drift(drift.clone())
+///     .build()
+///     .unwrap();
+/// client.scope(Card::named("Lightning Bolt")).await.unwrap();
+/// let report = drift.report();
+/// if !report.is_empty() {
+///     eprintln!("scryfall has changed:\n{report}");
+/// }
+/// # })
+/// ```
+#[derive(Clone, Default)]
+pub struct DriftRecorder {
+    report: Arc<Mutex<DriftReport>>,
+}
+
+impl fmt::Debug for DriftRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DriftRecorder")
+            .field("objects", &self.lock().objects)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DriftRecorder {
+    /// Creates a recorder with an empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A copy of what has been recorded so far.
+    pub fn report(&self) -> DriftReport {
+        self.lock().clone()
+    }
+
+    /// Takes what has been recorded so far, leaving the report empty.
+    pub fn take(&self) -> DriftReport {
+        std::mem::take(&mut *self.lock())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, DriftReport> {
+        self.report.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// The fields and enum values this crate didn't know about, with how many
+/// times each of them was seen.
+///
+/// Fields are named by the path to them from the object they were found in,
+/// like `Card.image_uris.holo` or `List<Card>.data[].sparkle_level`, and enum
+/// values by their enum, like `PromoType` and `"transparent"`.
+#[derive(Serialize, Clone, Default, PartialEq, Eq, Debug)]
+pub struct DriftReport {
+    objects: u64,
+    fields: BTreeMap<String, u64>,
+    variants: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl DriftReport {
+    /// The number of objects that were checked, whether or not anything
+    /// unknown was found in them.
+    pub fn objects(&self) -> u64 {
+        self.objects
+    }
+
+    /// Returns true if nothing unknown was found.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.variants.is_empty()
+    }
+
+    /// The unknown fields, by path, and the number of times each was seen.
+    pub fn unknown_fields(&self) -> impl Iterator<Item = (&str, u64)> + '_ {
+        self.fields
+            .iter()
+            .map(|(path, &count)| (path.as_str(), count))
+    }
+
+    /// The unknown enum values, as the name of their enum, the value, and the
+    /// number of times it was seen.
+    pub fn unknown_variants(&self) -> impl Iterator<Item = (&str, &str, u64)> + '_ {
+        self.variants.iter().flat_map(|(name, values)| {
+            values
+                .iter()
+                .map(move |(value, &count)| (name.as_str(), value.as_str(), count))
+        })
+    }
+
+    /// Adds the contents of `other` to this report.
+    pub fn merge(&mut self, other: DriftReport) {
+        self.objects += other.objects;
+        for (path, count) in other.fields {
+            *self.fields.entry(path).or_default() += count;
+        }
+        for (name, values) in other.variants {
+            let counts = self.variants.entry(name).or_default();
+            for (value, count) in values {
+                *counts.entry(value).or_default() += count;
+            }
+        }
+    }
+
+    fn field(&mut self, path: String) {
+        *self.fields.entry(path).or_default() += 1;
+    }
+
+    fn variant(&mut self, name: String, value: &str) {
+        *self
+            .variants
+            .entry(name)
+            .or_default()
+            .entry(value.to_string())
+            .or_default() += 1;
+    }
+}
+
+impl fmt::Display for DriftReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} objects checked", self.objects)?;
+        for (path, count) in self.unknown_fields() {
+            writeln!(f, "unknown field {path}: {count}")?;
+        }
+        for (name, value, count) in self.unknown_variants() {
+            writeln!(f, "unknown {name} {value:?}: {count}")?;
+        }
+        Ok(())
+    }
+}
+
+thread_local! {
+    /// The report for the object being deserialized on this thread, if its
+    /// drift is being recorded.
+    static ACTIVE: RefCell<Option<DriftReport>> = const { RefCell::new(None) };
+}
+
+/// Deserializes a `T`, adding its unknown fields and enum values to
+/// `recorder`, if there is one.
+pub(crate) fn deserialize<'de, T, D>(
+    deserializer: D,
+    recorder: Option<&DriftRecorder>,
+) -> Result<T, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let Some(recorder) = recorder else {
+        return T::deserialize(deserializer);
+    };
+    let outer = ACTIVE.with(|active| active.replace(Some(DriftReport::default())));
+    let mut fields = Vec::new();
+    let result = serde_ignored::deserialize(deserializer, |path| {
+        if let Some(path) = field_path(&path) {
+            fields.push(path);
+        }
+    });
+    let mut report = ACTIVE
+        .with(|active| active.replace(outer))
+        .unwrap_or_default();
+    if result.is_ok() {
+        let name = short_type_name::<T>();
+        report.objects += 1;
+        for path in fields {
+            report.field(format!("{name}{path}"));
+        }
+        recorder.lock().merge(report);
+    }
+    result
+}
+
+/// A [`DeserializeSeed`] that deserializes a `T` with [`deserialize`].
+pub(crate) struct Recorded<'a, T> {
+    recorder: Option<&'a DriftRecorder>,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<'a, T> Recorded<'a, T> {
+    pub(crate) fn new(recorder: Option<&'a DriftRecorder>) -> Self {
+        Recorded {
+            recorder,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for Recorded<'_, T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        deserialize(deserializer, self.recorder)
+    }
+}
+
+/// Deserializes the value of an unknown variant of `E`, recording it if the
+/// object it's in is being checked.
+#[cfg(feature = "unknown_variants")]
+pub(crate) fn unknown_variant<'de, E, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Box<str>, D::Error> {
+    let value = Box::<str>::deserialize(deserializer)?;
+    record_variant::<E>(&value);
+    Ok(value)
+}
+
+/// Deserializes the fields of `T` that this crate doesn't know about,
+/// recording them if the object they're in is being checked.
+///
+/// The fields reach this through `#[serde(flatten)]`, where the path to them is
+/// lost, so they're named after `T` instead, like `Price.usd_glossy`.
+/// Scryfall's `object` field isn't reported, as in [`field_path`].
+#[cfg(feature = "extra_fields")]
+pub(crate) fn extra_fields<'de, T, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<crate::extra_fields::ExtraFields, D::Error> {
+    let fields = crate::extra_fields::ExtraFields::deserialize(deserializer)?;
+    ACTIVE.with(|active| {
+        if let Some(report) = active.borrow_mut().as_mut() {
+            let name = short_type_name::<T>();
+            for key in fields.keys().filter(|&key| key != "object") {
+                report.field(format!("{name}.{key}"));
+            }
+        }
+    });
+    Ok(fields)
+}
+
+/// Records an unknown value of `E`, if the object it's in is being checked.
+pub(crate) fn record_variant<E>(value: &str) {
+    ACTIVE.with(|active| {
+        if let Some(report) = active.borrow_mut().as_mut() {
+            report.variant(short_type_name::<E>(), value);
+        }
+    });
+}
+
+/// The path to an ignored field, without the positions in arrays, so that the
+/// same field is counted together wherever it is. Scryfall's `object` field is
+/// left out, since it's never modeled.
+fn field_path(path: &Path) -> Option<String> {
+    fn write(path: &Path, out: &mut String) {
+        match path {
+            Path::Root => {},
+            Path::Seq { parent, .. } => {
+                write(parent, out);
+                out.push_str("[]");
+            },
+            Path::Map { parent, key } => {
+                write(parent, out);
+                out.push('.');
+                out.push_str(key);
+            },
+            Path::Some { parent }
+            | Path::NewtypeStruct { parent }
+            | Path::NewtypeVariant { parent } => write(parent, out),
+        }
+    }
+
+    if matches!(path, Path::Map { key, .. } if key == "object") {
+        return None;
+    }
+    let mut out = String::new();
+    write(path, &mut out);
+    Some(out)
+}
+
+/// The name of `T` without the paths of the modules its types are in, like
+/// `List<Card>`.
+fn short_type_name<T>() -> String {
+    let mut name = String::new();
+    let mut segment = 0;
+    let mut chars = type_name::<T>().chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ':' if chars.peek() == Some(&':') => {
+                chars.next();
+                name.truncate(segment);
+            },
+            c if c.is_alphanumeric() || c == '_' => name.push(c),
+            c => {
+                name.push(c);
+                segment = name.len();
+            },
+        }
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::card::Card;
+    use crate::list::List;
+    use crate::util::test_card::card_json;
+
+    fn check<T: serde::de::DeserializeOwned>(json: serde_json::Value) -> DriftReport {
+        let recorder = DriftRecorder::new();
+        deserialize::<T, _>(json, Some(&recorder)).unwrap();
+        recorder.take()
+    }
+
+    #[test]
+    fn names_types_without_their_modules() {
+        assert_eq!(short_type_name::<Card>(), "Card");
+        assert_eq!(short_type_name::<List<Card>>(), "List<Card>");
+        assert_eq!(
+            short_type_name::<Option<std::collections::HashMap<u8, String>>>(),
+            "Option<HashMap<u8, String>>"
+        );
+    }
+
+    #[test]
+    fn known_cards_have_no_drift() {
+        let report = check::<Card>(card_json(json!({})));
+        assert_eq!(report.objects(), 1);
+        assert!(report.is_empty(), "{report}");
+    }
+
+    #[test]
+    fn reports_unknown_fields() {
+        let report = check::<Card>(card_json(json!({
+            "sparkle_level": 11,
+            "prices": { "usd": "1.00", "usd_glossy": "2.50" },
+        })));
+        let fields = if cfg!(feature = "extra_fields") {
+            [("Card.sparkle_level", 1), ("Price.usd_glossy", 1)]
+        } else {
+            [("Card.prices.usd_glossy", 1), ("Card.sparkle_level", 1)]
+        };
+        assert_eq!(report.unknown_fields().collect::<Vec<_>>(), fields);
+
+        let page = json!({
+            "object": "list",
+            "has_more": false,
+            "data": [
+                card_json(json!({ "sparkle_level": 11 })),
+                card_json(json!({ "sparkle_level": 12 })),
+            ],
+        });
+        let report = check::<List<Card>>(page);
+        let field = if cfg!(feature = "extra_fields") {
+            "Card.sparkle_level"
+        } else {
+            "List<Card>.data[].sparkle_level"
+        };
+        assert_eq!(report.unknown_fields().collect::<Vec<_>>(), [(field, 2)]);
+    }
+
+    #[tokio::test]
+    async fn records_responses_and_bulk_data() {
+        use futures::StreamExt;
+
+        use crate::client::RateLimiter;
+        use crate::util::streaming_deserializer;
+        use crate::util::test_server::TestServer;
+        use crate::ScryfallClient;
+
+        let body = card_json(json!({ "sparkle_level": 11 })).to_string();
+        let server = TestServer::start(vec![(200, body.clone())]).await;
+        let drift = DriftRecorder::new();
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .schema_drift(drift.clone())
+            .build()
+            .unwrap();
+        client.scope(Card::named("Lightning Bolt")).await.unwrap();
+        assert_eq!(
+            drift.report().unknown_fields().collect::<Vec<_>>(),
+            [("Card.sparkle_level", 1)]
+        );
+
+        let bulk = format!("[{body},{body}]");
+        let cards = streaming_deserializer::create(std::io::Cursor::new(bulk), Some(drift.clone()))
+            .collect::<Vec<crate::Result<Card>>>()
+            .await;
+        assert!(cards.iter().all(Result::is_ok));
+        let report = drift.take();
+        assert_eq!(report.objects(), 3);
+        assert_eq!(
+            report.unknown_fields().collect::<Vec<_>>(),
+            [("Card.sparkle_level", 3)]
+        );
+        assert!(drift.report().is_empty());
+    }
+
+    #[test]
+    fn reports_unknown_formats() {
+        let mut json = card_json(json!({}));
+        json["legalities"]["tinyleaders"] = json!("legal");
+        let report = check::<Card>(json);
+        assert_eq!(
+            report.unknown_variants().collect::<Vec<_>>(),
+            [("Format", "tinyleaders", 1)]
+        );
+    }
+
+    #[cfg(feature = "unknown_variants")]
+    #[test]
+    fn reports_unknown_enum_values() {
+        let report = check::<Card>(card_json(json!({
+            "promo_types": ["prerelease", "transparent", "sparklefoil"],
+            "frame_effects": ["legendary", "transparent"],
+            "set_type": "jumpstart_plus",
+        })));
+        assert_eq!(
+            report.unknown_variants().collect::<Vec<_>>(),
+            [
+                ("FrameEffect", "transparent", 1),
+                ("PromoType", "sparklefoil", 1),
+                ("PromoType", "transparent", 1),
+                ("SetType", "jumpstart_plus", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_add_up() {
+        let mut report = check::<Card>(card_json(json!({})));
+        let mut json = card_json(json!({}));
+        json["legalities"]["tinyleaders"] = json!("legal");
+        report.merge(check::<Card>(json.clone()));
+        report.merge(check::<Card>(json));
+        assert_eq!(report.objects(), 3);
+        assert_eq!(
+            report.to_string(),
+            "3 objects checked\nunknown Format \"tinyleaders\": 2\n"
+        );
+    }
+}
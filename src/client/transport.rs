@@ -0,0 +1,268 @@
+//! The layer that sends HTTP requests on behalf of a
+//! [`ScryfallClient`](super::ScryfallClient).
+//!
+//! Everything the client does above the network, such as rate limiting,
+//! retrying and caching, is independent of how requests are actually sent.
+//! That last step is delegated to a [`Transport`], which takes a [`Request`]
+//! and returns the status, headers and body of the [`Response`].
+//!
+//! With the `reqwest` feature, which is enabled by default, clients use a
+//! [`ReqwestTransport`] unless told otherwise. A different HTTP stack, or an
+//! in-memory fake for tests, can be plugged in by implementing [`Transport`]
+//! and passing it to
+//! [`ScryfallClientBuilder::transport`](super::ScryfallClientBuilder::transport).
+//!
+//! # Examples
+//! ```rust
+//! use bytes::Bytes;
+//! use futures::stream::{self, StreamExt};
+//! use scryfall::client::transport::{Request, Response, Transport, TransportError};
+//! use scryfall::{Catalog, ScryfallClient};
+//!
+//! /// Answers every request with the same catalog.
+//! #[derive(Debug)]
+//! struct Fake;
+//!
+//! #[async_trait::async_trait]
+//! impl Transport for Fake {
+//!     async fn send(&self, _: Request) -> Result<Response, TransportError> {
+//!         let body = Bytes::from_static(
+//!             br#"{"uri":"https://api.scryfall.com/catalog/powers","total_values":1,"data":["*"]}"#,
+//!         );
+//!         Ok(Response::new(200, Default::default(), stream::iter([Ok(body)]).boxed()))
+//!     }
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! let client = ScryfallClient::builder().transport(Fake).build().unwrap();
+//! let powers = client.scope(Catalog::powers()).await.unwrap();
+//! assert_eq!(powers.data, ["*"]);
+//! # })
+//! ```
+use std::error::Error as StdError;
+use std::time::Duration;
+use std::{fmt, io};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::BoxStream;
+pub use http::{HeaderMap, Method};
+use url::Url;
+
+/// The body of a [`Response`], as a stream of chunks.
+pub type Body = BoxStream<'static, io::Result<Bytes>>;
+
+/// Sends HTTP requests.
+///
+/// Implementations only need to send the request as described and hand back
+/// whatever the server answered. Responses with error statuses are not
+/// errors at this level, and redirects may be followed or not.
+#[async_trait]
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Sends `request`, returning the response once its headers have been
+    /// received.
+    async fn send(&self, request: Request) -> Result<Response, TransportError>;
+}
+
+/// A description of an HTTP request.
+#[derive(Clone, Debug)]
+pub struct Request {
+    /// The request method.
+    pub method: Method,
+    /// The url to send the request to.
+    pub url: Url,
+    /// The headers to send.
+    pub headers: HeaderMap,
+    /// How long the whole request may take, from connecting until the body has
+    /// been read, if it should be limited.
+    pub timeout: Option<Duration>,
+    /// The request body, if there is one.
+    pub body: Option<Bytes>,
+}
+
+impl Request {
+    /// Creates a request with no headers, timeout or body.
+    pub fn new(method: Method, url: Url) -> Self {
+        Request {
+            method,
+            url,
+            headers: HeaderMap::new(),
+            timeout: None,
+            body: None,
+        }
+    }
+}
+
+/// The response to a [`Request`].
+pub struct Response {
+    /// The status code.
+    pub status: u16,
+    /// The response headers.
+    pub headers: HeaderMap,
+    /// The response body, which is only read if and when it's needed.
+    pub body: Body,
+}
+
+impl Response {
+    /// Creates a response.
+    pub fn new(status: u16, headers: HeaderMap, body: Body) -> Self {
+        Response {
+            status,
+            headers,
+            body,
+        }
+    }
+}
+
+impl fmt::Debug for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Response")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An error sending a request through a [`Transport`].
+#[derive(Debug)]
+pub struct TransportError {
+    kind: TransportErrorKind,
+    source: Box<dyn StdError + Send + Sync>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum TransportErrorKind {
+    Connect,
+    Timeout,
+    Other,
+}
+
+impl TransportError {
+    /// The connection to the server couldn't be established.
+    pub fn connect(source: impl Into<Box<dyn StdError + Send + Sync>>) -> Self {
+        TransportError {
+            kind: TransportErrorKind::Connect,
+            source: source.into(),
+        }
+    }
+
+    /// The request took longer than its [timeout](Request::timeout).
+    pub fn timeout(source: impl Into<Box<dyn StdError + Send + Sync>>) -> Self {
+        TransportError {
+            kind: TransportErrorKind::Timeout,
+            source: source.into(),
+        }
+    }
+
+    /// Any other failure.
+    pub fn other(source: impl Into<Box<dyn StdError + Send + Sync>>) -> Self {
+        TransportError {
+            kind: TransportErrorKind::Other,
+            source: source.into(),
+        }
+    }
+
+    /// Returns true if the connection to the server couldn't be established.
+    pub fn is_connect(&self) -> bool {
+        self.kind == TransportErrorKind::Connect
+    }
+
+    /// Returns true if the request timed out.
+    pub fn is_timeout(&self) -> bool {
+        self.kind == TransportErrorKind::Timeout
+    }
+
+    /// The underlying error, which can be downcast to the error type of the
+    /// transport.
+    pub fn get_ref(&self) -> &(dyn StdError + Send + Sync + 'static) {
+        &*self.source
+    }
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.source, f)
+    }
+}
+
+impl StdError for TransportError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+/// A transport that fails every request, used when the crate is built without
+/// any HTTP client and no transport was configured.
+#[cfg(not(feature = "reqwest"))]
+#[derive(Debug)]
+pub(crate) struct NoTransport;
+
+#[cfg(not(feature = "reqwest"))]
+#[async_trait]
+impl Transport for NoTransport {
+    async fn send(&self, _: Request) -> Result<Response, TransportError> {
+        Err(TransportError::other(
+            "no HTTP transport configured: enable the `reqwest` feature or \
+             set one with `ScryfallClientBuilder::transport`",
+        ))
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "reqwest")] {
+        use futures::TryStreamExt;
+        use futures::StreamExt;
+
+        /// A [`Transport`] backed by a [`reqwest::Client`].
+        ///
+        /// This is the default transport when the `reqwest` feature is enabled.
+        #[cfg_attr(docsrs, doc(cfg(feature = "reqwest")))]
+        #[derive(Clone, Debug, Default)]
+        pub struct ReqwestTransport {
+            client: reqwest::Client,
+        }
+
+        impl ReqwestTransport {
+            /// Creates a transport with a default [`reqwest::Client`].
+            pub fn new() -> Self {
+                Self::default()
+            }
+        }
+
+        impl From<reqwest::Client> for ReqwestTransport {
+            fn from(client: reqwest::Client) -> Self {
+                ReqwestTransport { client }
+            }
+        }
+
+        #[async_trait]
+        impl Transport for ReqwestTransport {
+            async fn send(&self, request: Request) -> Result<Response, TransportError> {
+                let mut builder = self
+                    .client
+                    .request(request.method, request.url)
+                    .headers(request.headers);
+                if let Some(timeout) = request.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(body) = request.body {
+                    builder = builder.body(body);
+                }
+                let response = builder.send().await.map_err(|e| {
+                    if e.is_timeout() {
+                        TransportError::timeout(e)
+                    } else if e.is_connect() {
+                        TransportError::connect(e)
+                    } else {
+                        TransportError::other(e)
+                    }
+                })?;
+                Ok(Response {
+                    status: response.status().as_u16(),
+                    headers: response.headers().clone(),
+                    body: response.bytes_stream().map_err(io::Error::other).boxed(),
+                })
+            }
+        }
+    }
+}
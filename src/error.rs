@@ -4,11 +4,12 @@ use std::{fmt, io};
 
 use httpstatus::StatusCode;
 use itertools::Itertools;
-use reqwest::Error as ReqwestError;
 use serde::{Deserialize, Serialize};
 use serde_json::Error as SerdeError;
 use url::ParseError as UrlParseError;
 
+use crate::client::transport::TransportError;
+
 /// The errors that may occur when interacting with the scryfall API.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -28,9 +29,10 @@ pub enum Error {
 
     /// Something went wrong when making the HTTP request.
     #[error("Error making request to {url}: {error}")]
-    ReqwestError {
-        /// The error reqwest returned.
-        error: Box<ReqwestError>,
+    TransportError {
+        /// The error the [`Transport`](crate::client::transport::Transport)
+        /// returned.
+        error: Box<TransportError>,
         /// The url that was involved.
         url: url::Url,
     },
@@ -52,6 +54,38 @@ pub enum Error {
     Other(String),
 }
 
+impl Error {
+    /// The HTTP status code of the response that caused this error, if the
+    /// error came from a response.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::ScryfallError(e) => Some(e.status),
+            Error::HttpError(status) => Some(status.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this error is likely transient, and repeating the same
+    /// request later may succeed.
+    ///
+    /// This is the case for `429 Too Many Requests` and `5xx` responses, and
+    /// for requests that failed to connect or timed out.
+    pub fn is_retryable(&self) -> bool {
+        match self.status() {
+            Some(status) => is_retryable_status(status),
+            None => match self {
+                Error::TransportError { error, .. } => error.is_timeout() || error.is_connect(),
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Returns true if a response with this status should be retried.
+pub(crate) fn is_retryable_status(status: u16) -> bool {
+    status == 429 || (500..=599).contains(&status)
+}
+
 impl From<SerdeError> for Box<Error> {
     fn from(err: SerdeError) -> Self {
         Box::new(err.into())
@@ -69,7 +103,6 @@ impl From<UrlParseError> for Box<Error> {
 ///
 /// [Official docs](https://scryfall.com/docs/api/errors)
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct ScryfallError {
     /// An integer HTTP status code for this error.
     pub status: u16,
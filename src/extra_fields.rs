@@ -0,0 +1,178 @@
+//! Fields Scryfall sent that this crate doesn't know about yet.
+//!
+//! Scryfall adds fields to its objects from time to time, and until this crate
+//! is updated they're dropped when deserializing. With the `extra_fields`
+//! feature enabled, [`Card`](crate::Card), [`Set`](crate::Set) and the other
+//! objects Scryfall returns gain an `extra` field where every unrecognized
+//! field is kept, with its original value. This includes the `object` field
+//! naming the kind of object, which this crate doesn't model. Serializing the
+//! object writes them back out, so an object read from Scryfall and written
+//! again has the same content, which is useful to cache Scryfall's responses.
+//!
+//! ```rust
+//! use scryfall::set::Set;
+//! # use serde_json::json;
+//!
+//! let json = json!({
+//!     "object": "set",
+//!     "id": "2ec77b94-6d47-4891-a480-5d0b4e5c9372",
+//!     "code": "uma",
+//!     "name": "Ultimate Masters",
+//!     "uri": "https://api.scryfall.com/sets/uma",
+//!     "scryfall_uri": "https://scryfall.com/sets/uma",
+//!     "search_uri": "https://api.scryfall.com/cards/search?order=set&q=e%3Auma&unique=prints",
+//!     "released_at": "2018-12-07",
+//!     "set_type": "masters",
+//!     "card_count": 254,
+//!     "digital": false,
+//!     "nonfoil_only": false,
+//!     "foil_only": false,
+//!     "icon_svg_uri": "https://svgs.scryfall.io/sets/uma.svg",
+//!     "sparkle_level": 11,
+//! });
+//! let set: Set = serde_json::from_value(json.clone()).unwrap();
+//! assert_eq!(set.extra["sparkle_level"], 11);
+//! assert_eq!(set.extra["object"], "set");
+//! assert_eq!(serde_json::to_value(&set).unwrap(), json);
+//! ```
+//!
+//! To write objects the way Scryfall does, known fields that are missing are
+//! left out rather than written as `null` or as their default, except in the
+//! objects where Scryfall always writes every field, like the
+//! [prices](crate::card::Price) of a card and [card
+//! symbols](crate::symbology::CardSymbol).
+use std::cmp::Ordering;
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The fields of an object that this crate doesn't model, by name.
+///
+/// This dereferences to a [`serde_json::Map`], so fields can be read and
+/// changed like in any other json object.
+#[derive(Serialize, Deserialize, Default, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(transparent)]
+pub struct ExtraFields(Map<String, Value>);
+
+impl ExtraFields {
+    /// Creates an empty set of fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Unwraps the json object holding the fields.
+    pub fn into_inner(self) -> Map<String, Value> {
+        self.0
+    }
+}
+
+impl Deref for ExtraFields {
+    type Target = Map<String, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ExtraFields {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Map<String, Value>> for ExtraFields {
+    fn from(fields: Map<String, Value>) -> Self {
+        ExtraFields(fields)
+    }
+}
+
+impl FromIterator<(String, Value)> for ExtraFields {
+    fn from_iter<I: IntoIterator<Item = (String, Value)>>(iter: I) -> Self {
+        ExtraFields(iter.into_iter().collect())
+    }
+}
+
+/// Fields are compared by name, then by their json text, so that types
+/// holding them can still be ordered.
+impl Ord for ExtraFields {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let entries = |fields: &ExtraFields| {
+            fields
+                .0
+                .iter()
+                .map(|(name, value)| (name.clone(), value.to_string()))
+                .collect::<Vec<_>>()
+        };
+        entries(self).cmp(&entries(other))
+    }
+}
+
+impl PartialOrd for ExtraFields {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::card::{Card, CardStore};
+    use crate::util::test_card::{card, card_json};
+
+    #[test]
+    fn cards_keep_fields_they_do_not_know() {
+        let json = card_json(json!({
+            "sparkle_level": 11,
+            "image_uris": {
+                "small": "https://cards.scryfall.io/small/front/e/3/bolt.jpg",
+                "holo": "https://cards.scryfall.io/holo/front/e/3/bolt.jpg",
+            },
+            "prices": { "usd": "1.00", "usd_glossy": "2.50" },
+        }));
+        let card: Card = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(card.extra["sparkle_level"], 11);
+        assert_eq!(card.prices.extra["usd_glossy"], "2.50");
+        let back = serde_json::to_value(&card).unwrap();
+        assert_eq!(back["sparkle_level"], 11);
+        assert_eq!(back["image_uris"]["holo"], json["image_uris"]["holo"]);
+        assert_eq!(back["prices"]["usd_glossy"], "2.50");
+        assert_eq!(serde_json::from_value::<Card>(back).unwrap(), card);
+    }
+
+    #[test]
+    fn writes_cards_back_like_scryfall_sent_them() {
+        let json = card_json(json!({
+            "sparkle_level": 11,
+            "frame_effects": ["legendary"],
+            "image_uris": { "small": "https://cards.scryfall.io/small/front/e/3/bolt.jpg" },
+        }));
+        let card: Card = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&card).unwrap(), json);
+    }
+
+    #[test]
+    fn known_cards_have_no_extra_fields() {
+        let bolt = card(json!({}));
+        assert!(bolt.extra.is_empty());
+        assert!(bolt.prices.extra.is_empty());
+    }
+
+    #[test]
+    fn card_stores_keep_extra_fields() {
+        let card: Card = serde_json::from_value(card_json(json!({
+            "sparkle_level": 11,
+            "related_uris": { "gatherer": null, "wiki": "https://example.com" },
+            "purchase_uris": {
+                "tcgplayer": "https://example.com/tcg",
+                "cardmarket": "https://example.com/mkm",
+                "cardhoarder": "https://example.com/ch",
+                "local_shop": "https://example.com/shop",
+            },
+        })))
+        .unwrap();
+        let store = CardStore::from_iter([card.clone()]);
+        assert_eq!(store.get(0), Some(card));
+    }
+}
@@ -1,11 +1,19 @@
 //! The available magic the gathering formats.
 use std::fmt;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(not(feature = "unknown_variants"), derive(Copy))]
+#[cfg_attr(
+    all(
+        not(feature = "unknown_variants"),
+        not(feature = "unknown_variants_slim")
+    ),
+    non_exhaustive
+)]
 #[allow(missing_docs)]
-#[non_exhaustive]
 pub enum Format {
     Standard,
     Modern,
@@ -32,41 +40,147 @@ pub enum Format {
     HistoricBrawl,
     CompetitiveBrawl,
     TLR,
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
+    )]
+    #[cfg(feature = "unknown_variants")]
+    #[serde(
+        untagged,
+        deserialize_with = "crate::client::schema_drift::unknown_variant::<Format, _>"
+    )]
+    /// Unknown format
+    Unknown(Box<str>),
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
+    )]
+    #[cfg(all(not(feature = "unknown_variants"), feature = "unknown_variants_slim"))]
+    #[serde(other)]
+    /// Unknown format
+    Unknown,
 }
 
-impl fmt::Display for Format {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+/// Every format this crate knows about.
+pub(crate) const KNOWN: [Format; 25] = [
+    Format::Standard,
+    Format::Modern,
+    Format::Legacy,
+    Format::Vintage,
+    Format::Commander,
+    Format::Future,
+    Format::Pauper,
+    Format::Pioneer,
+    Format::Penny,
+    Format::Duel,
+    Format::OldSchool,
+    Format::Historic,
+    Format::Gladiator,
+    Format::Brawl,
+    Format::Premodern,
+    Format::PauperCommander,
+    Format::Alchemy,
+    Format::Explorer,
+    Format::Predh,
+    Format::Oathbreaker,
+    Format::Timeless,
+    Format::StandardBrawl,
+    Format::HistoricBrawl,
+    Format::CompetitiveBrawl,
+    Format::TLR,
+];
+
+impl Format {
+    /// The name Scryfall uses for this format, like `"paupercommander"`.
+    pub fn as_str(&self) -> &str {
         use Format::*;
-        write!(
-            f,
-            "{}",
-            match self {
-                Standard => "standard",
-                Modern => "modern",
-                Legacy => "legacy",
-                Vintage => "vintage",
-                Commander => "commander",
-                Future => "future",
-                Pauper => "pauper",
-                Pioneer => "pioneer",
-                Penny => "penny",
-                Duel => "duel",
-                OldSchool => "oldschool",
-                Historic => "historic",
-                Gladiator => "gladiator",
-                Brawl => "brawl",
-                Premodern => "premodern",
-                PauperCommander => "paupercommander",
-                Alchemy => "alchemy",
-                Explorer => "explorer",
-                Predh => "predh",
-                Oathbreaker => "oathbreaker",
-                Timeless => "timeless",
-                StandardBrawl => "standardbrawl",
-                HistoricBrawl => "historicbrawl",
-                CompetitiveBrawl => "competitivebrawl",
-                TLR => "tlr",
+        match self {
+            Standard => "standard",
+            Modern => "modern",
+            Legacy => "legacy",
+            Vintage => "vintage",
+            Commander => "commander",
+            Future => "future",
+            Pauper => "pauper",
+            Pioneer => "pioneer",
+            Penny => "penny",
+            Duel => "duel",
+            OldSchool => "oldschool",
+            Historic => "historic",
+            Gladiator => "gladiator",
+            Brawl => "brawl",
+            Premodern => "premodern",
+            PauperCommander => "paupercommander",
+            Alchemy => "alchemy",
+            Explorer => "explorer",
+            Predh => "predh",
+            Oathbreaker => "oathbreaker",
+            Timeless => "timeless",
+            StandardBrawl => "standardbrawl",
+            HistoricBrawl => "historicbrawl",
+            CompetitiveBrawl => "competitivebrawl",
+            TLR => "tlr",
+            #[cfg(feature = "unknown_variants")]
+            Unknown(name) => name,
+            #[cfg(all(not(feature = "unknown_variants"), feature = "unknown_variants_slim"))]
+            Unknown => "unknown-format",
+        }
+    }
+}
+
+/// The position in [`KNOWN`] of the format called `name`, if this crate knows
+/// about it.
+pub(crate) fn known_index(name: &str) -> Option<usize> {
+    KNOWN.iter().position(|format| format.as_str() == name)
+}
+
+impl FromStr for Format {
+    type Err = ParseFormatError;
+
+    /// Parses the name Scryfall uses for a format, like `"paupercommander"`.
+    /// Names of formats this crate doesn't know about are only accepted with
+    /// one of the `unknown_variants` features.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(format) = KNOWN.iter().find(|format| format.as_str() == s).cloned() {
+            return Ok(format);
+        }
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "unknown_variants")] {
+                Ok(Format::Unknown(s.into()))
+            } else if #[cfg(feature = "unknown_variants_slim")] {
+                Ok(Format::Unknown)
+            } else {
+                Err(ParseFormatError {
+                    format: s.to_string(),
+                })
             }
-        )
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The error returned when the name of a format isn't known.
+#[derive(Clone, Eq, PartialEq, Debug, thiserror::Error)]
+#[error("unknown format {format:?}")]
+pub struct ParseFormatError {
+    format: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_scryfall_names() {
+        for format in KNOWN {
+            assert_eq!(format.to_string().parse::<Format>(), Ok(format));
+        }
+        #[cfg(not(any(feature = "unknown_variants", feature = "unknown_variants_slim")))]
+        assert!("spellslinger".parse::<Format>().is_err());
     }
 }
@@ -67,15 +67,52 @@
 //! For this the [`search`] module provides a type safe api
 //! to interact and query the search engine. For advanced features like
 //! sorting and collation, see [`search::advanced`].
+//!
+//! ## Configuring requests
+//!
+//! All requests are made through a [`ScryfallClient`]. The [`client`] module
+//! explains how to set a custom user agent, timeouts or base url, either for
+//! the whole program or for a single piece of work.
+//!
+//! ## Blocking API
+//!
+//! With the `blocking` feature enabled, the `blocking` module offers the same
+//! lookups as plain functions that wait for their result, for programs that
+//! don't use async. They still make their requests on a tokio runtime, which
+//! the module starts and owns, so the feature enables tokio's multi-threaded
+//! runtime.
+//!
+//! ## Tracing
+//!
+//! With the `tracing` feature enabled, every request is made inside a
+//! `scryfall_request` span recording its method, url, cache outcome, status,
+//! retries, latency and body size. Pagination and bulk data downloads and
+//! parsing emit events too. Nothing is logged without a subscriber.
+//!
+//! ## Extra fields
+//!
+//! With the `extra_fields` feature enabled, fields Scryfall sends that this
+//! crate doesn't know about are kept on the objects that hold them and
+//! written back out when serializing, see the `extra_fields` module.
+#[cfg(feature = "blocking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+pub mod blocking;
 pub mod bulk;
 pub mod card;
 pub mod catalog;
+pub mod client;
 pub mod error;
+#[cfg(feature = "extra_fields")]
+#[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+pub mod extra_fields;
 pub mod format;
 pub mod list;
+pub mod migration;
+pub mod oracle;
 pub mod ruling;
 pub mod search;
 pub mod set;
+pub mod symbology;
 pub mod uri;
 mod util;
 
@@ -85,6 +122,7 @@ pub type Result<T> = std::result::Result<T, error::Error>;
 
 pub use card::Card;
 pub use catalog::Catalog;
+pub use client::ScryfallClient;
 pub use error::Error;
 pub use ruling::Ruling;
 pub use set::Set;
@@ -99,6 +137,7 @@ mod tests {
 
     use crate::search::prelude::*;
     use crate::set::{Set, SetCode};
+    use crate::util::test_cassette::with_cassette;
 
     #[test]
     fn set_code_serde_test() {
@@ -114,64 +153,131 @@ mod tests {
     #[tokio::test]
     #[ignore]
     async fn all_sets() {
-        Set::all()
-            .await
-            .unwrap()
-            .into_stream()
-            .map(Result::unwrap)
-            .for_each(|set| async move {
-                assert!(set.code.get().len() >= 3);
-            })
-            .await;
+        with_cassette("all_sets", async {
+            Set::all()
+                .await
+                .unwrap()
+                .into_stream()
+                .map(Result::unwrap)
+                .for_each(|set| async move {
+                    assert!(set.code.get().len() >= 3);
+                })
+                .await;
+        })
+        .await
     }
 
     #[tokio::test]
     #[ignore]
     async fn all_sets_buffered() {
-        Set::all()
-            .await
-            .unwrap()
-            .into_stream_buffered(10)
-            .map(Result::unwrap)
-            .for_each(|set| async move {
-                assert!(set.code.get().len() >= 3);
-            })
-            .await;
+        with_cassette("all_sets_buffered", async {
+            Set::all()
+                .await
+                .unwrap()
+                .into_stream_buffered(10)
+                .map(Result::unwrap)
+                .for_each(|set| async move {
+                    assert!(set.code.get().len() >= 3);
+                })
+                .await;
+        })
+        .await
     }
 
     #[tokio::test]
     #[ignore]
     async fn latest_cards() {
-        Set::all()
-            .await
-            .unwrap()
-            .into_stream()
-            .map(Result::unwrap)
-            .take(30)
-            .for_each_concurrent(None, |s| async move {
-                let set_cards = set(s.code).search().await;
-                if let Err(e) = set_cards {
-                    println!("Could not search for cards in '{}' - {}", s.name, e);
-                }
-            })
-            .await;
+        with_cassette("latest_cards", async {
+            Set::all()
+                .await
+                .unwrap()
+                .into_stream()
+                .map(Result::unwrap)
+                .take(30)
+                .for_each_concurrent(None, |s| async move {
+                    let set_cards = set(s.code).search().await;
+                    if let Err(e) = set_cards {
+                        println!("Could not search for cards in '{}' - {}", s.name, e);
+                    }
+                })
+                .await;
+        })
+        .await
     }
 
     #[tokio::test]
     #[ignore]
     async fn latest_cards_buffered() {
-        Set::all()
-            .await
-            .unwrap()
-            .into_stream_buffered(10)
-            .map(Result::unwrap)
-            .take(30)
-            .for_each_concurrent(None, |s| async move {
-                let set_cards = set(s.code).search().await;
-                if let Err(e) = set_cards {
-                    println!("Could not search for cards in '{}' - {}", s.name, e);
-                }
+        with_cassette("latest_cards_buffered", async {
+            Set::all()
+                .await
+                .unwrap()
+                .into_stream_buffered(10)
+                .map(Result::unwrap)
+                .take(30)
+                .for_each_concurrent(None, |s| async move {
+                    let set_cards = set(s.code).search().await;
+                    if let Err(e) = set_cards {
+                        println!("Could not search for cards in '{}' - {}", s.name, e);
+                    }
+                })
+                .await;
+        })
+        .await
+    }
+
+    /// Lists sets from a made up response, not one recorded from Scryfall.
+    /// The ignored tests above check the real API.
+    #[tokio::test]
+    async fn lists_sets_from_a_synthetic_response() {
+        use serde_json::json;
+
+        use crate::client::RateLimiter;
+        use crate::util::test_server::TestServer;
+        use crate::ScryfallClient;
+
+        let set = |code: &str, name: &str| {
+            json!({
+                "object": "set",
+                "id": "2ec77b94-6d47-4891-a480-5d0b4e5c9372",
+                "code": code,
+                "name": name,
+                "uri": format!("https://api.scryfall.com/sets/{code}"),
+                "scryfall_uri": format!("https://scryfall.com/sets/{code}"),
+                "search_uri": format!("https://api.scryfall.com/cards/search?q=e%3A{code}"),
+                "released_at": "2009-07-17",
+                "set_type": "core",
+                "card_count": 249,
+                "digital": false,
+                "nonfoil_only": false,
+                "foil_only": false,
+                "icon_svg_uri": format!("https://svgs.scryfall.io/sets/{code}.svg"),
+            })
+        };
+        let page = json!({
+            "object": "list",
+            "has_more": false,
+            "data": [set("m10", "Magic 2010"), set("m11", "Magic 2011")],
+        });
+        let server = TestServer::start(vec![(200, page.to_string())]).await;
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .build()
+            .unwrap();
+
+        let names = client
+            .scope(async {
+                Set::all()
+                    .await
+                    .unwrap()
+                    .into_stream()
+                    .map(|set| set.unwrap().name)
+                    .collect::<Vec<_>>()
+                    .await
             })
             .await;
+        assert_eq!(names, ["Magic 2010", "Magic 2011"]);
+        assert!(server.requests()[0].starts_with("GET /sets"));
     }
 }
@@ -11,7 +11,9 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::vec;
 
+use crate::client::ScryfallClient;
 use crate::uri::Uri;
+use crate::util::trace;
 
 /// A List object represents a requested sequence of other objects (Cards, Sets,
 /// etc). List objects may be paginated, and also include information about
@@ -21,7 +23,6 @@ use crate::uri::Uri;
 ///
 /// For more information, visit the [official docs](https://scryfall.com/docs/api/lists).
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct List<T> {
     /// An array of the requested objects, in a specific order.
     pub data: Vec<T>,
@@ -57,11 +58,17 @@ impl<T: DeserializeOwned + Send + Sync + Unpin> List<T> {
         PageIter {
             curr: Some(self),
             page_num: 1,
+            client: ScryfallClient::current(),
         }
     }
 
-    /// Creates a ListIter from a List
+    /// Creates a ListIter from a List. Further pages will be requested through
+    /// the [current](ScryfallClient::current) client.
     pub fn into_list_iter(self) -> ListIter<T> {
+        self.into_list_iter_with(ScryfallClient::current())
+    }
+
+    pub(crate) fn into_list_iter_with(self, client: ScryfallClient) -> ListIter<T> {
         // `has_more` is assumed to be redundant.
         debug_assert!(self.has_more == self.next_page.is_some());
 
@@ -71,6 +78,7 @@ impl<T: DeserializeOwned + Send + Sync + Unpin> List<T> {
             page_num: 1,
             total: self.total_cards,
             remaining: self.total_cards,
+            client,
         }
     }
 }
@@ -89,6 +97,7 @@ pub struct ListIter<T> {
     page_num: usize,
     total: Option<usize>,
     remaining: Option<usize>,
+    client: ScryfallClient,
 }
 
 impl<T: DeserializeOwned + Send + Sync + Unpin + 'static> ListIter<T> {
@@ -108,15 +117,16 @@ impl<T: DeserializeOwned + Send + Sync + Unpin + 'static> ListIter<T> {
     ///         .unwrap()
     ///         .unwrap()
     ///         .collector_number
-    ///         .parse::<usize>()
-    ///         .unwrap(),
+    ///         .number()
+    ///         .unwrap() as usize,
     ///     page_1.into_inner().len() + 1
     /// );
     /// # })
     /// ```
     pub async fn next_page(&self) -> crate::Result<Option<Self>> {
         if let Some(uri) = self.next_uri.as_ref() {
-            let mut new_iter = uri.fetch_iter().await?;
+            trace::page(self.page_num + 1, self.remaining);
+            let mut new_iter = uri.fetch_iter_with(&self.client).await?;
             new_iter.remaining = self.remaining.map(|r| r - self.inner.len());
             new_iter.page_num = self.page_num + 1;
 
@@ -267,21 +277,25 @@ impl<T: DeserializeOwned + Send + Sync + Unpin + 'static> ListIter<T> {
 pub struct PageIter<T> {
     curr: Option<List<T>>,
     page_num: usize,
+    client: ScryfallClient,
 }
 
 impl<T: DeserializeOwned + Send + Sync + Unpin> PageIter<T> {
     async fn stream_next(&mut self) -> Option<impl Future<Output = List<T>>> {
         if let Some(curr) = self.curr.take() {
             self.curr = match &curr.next_page {
-                Some(uri) => match uri.fetch().await {
-                    Ok(page) => {
-                        self.page_num += 1;
-                        Some(page)
-                    },
-                    Err(e) => {
-                        eprintln!("Error fetching page {} - {}", self.page_num + 1, e);
-                        None
-                    },
+                Some(uri) => {
+                    trace::page(self.page_num + 1, None);
+                    match uri.fetch_with(&self.client).await {
+                        Ok(page) => {
+                            self.page_num += 1;
+                            Some(page)
+                        },
+                        Err(e) => {
+                            eprintln!("Error fetching page {} - {}", self.page_num + 1, e);
+                            None
+                        },
+                    }
                 },
                 None => None,
             };
@@ -0,0 +1,299 @@
+//! Migrations record the Scryfall card ids that were merged into another card
+//! or deleted from the database.
+//!
+//! Programs that keep their own copy of Scryfall's cards can use them to drop
+//! or replace the ids that no longer exist, see [`apply_all`].
+//!
+//! Visit the official [docs](https://scryfall.com/docs/api/migrations) for more documentation.
+
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
+
+use crate::list::ListIter;
+use crate::uri::Uri;
+use crate::util::MIGRATIONS_URL;
+
+/// A change to a Scryfall card id, either merging it into another card or
+/// deleting it.
+///
+/// For more information, refer to the [official docs](https://scryfall.com/docs/api/migrations).
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub struct Migration {
+    /// The id of this migration.
+    pub id: Uuid,
+
+    /// A link to this migration object on Scryfall's API.
+    pub uri: Url,
+
+    /// The date this migration was performed.
+    pub performed_at: NaiveDate,
+
+    /// What happened to the old card id.
+    pub migration_strategy: MigrationStrategy,
+
+    /// The id of the card that was migrated.
+    pub old_scryfall_id: Uuid,
+
+    /// The id of the card the old one was merged into. Only present when the
+    /// strategy is [`Merge`](MigrationStrategy::Merge).
+    #[serde(default)]
+    pub new_scryfall_id: Option<Uuid>,
+
+    /// A note left by the Scryfall team about this migration.
+    #[serde(default)]
+    pub note: Option<String>,
+
+    /// Additional context Scryfall has provided for this migration, in an
+    /// unspecified format.
+    #[serde(default)]
+    pub metadata: Option<serde_json::Value>,
+
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<Migration, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
+
+    #[cfg(test)]
+    #[serde(rename = "object")]
+    _object: String,
+}
+
+/// What happened to a migrated card id.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationStrategy {
+    /// The card was merged into another card, and its records should be
+    /// updated to point at the new id.
+    Merge,
+    /// The card was deleted, and its records should be removed.
+    Delete,
+}
+
+impl Migration {
+    /// Returns a [`ListIter`] of all the migrations Scryfall has performed,
+    /// newest first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::migration::Migration;
+    /// # tokio_test::block_on(async {
+    /// let mut migrations = Migration::all().await.unwrap();
+    /// let latest = migrations.next().await.unwrap().unwrap();
+    /// assert!(latest.performed_at > chrono::NaiveDate::from_ymd_opt(2019, 1, 1).unwrap());
+    /// # })
+    /// ```
+    pub async fn all() -> crate::Result<ListIter<Migration>> {
+        Uri::from(MIGRATIONS_URL.clone()).fetch_iter().await
+    }
+
+    /// Applies this migration to a map of records keyed by card id.
+    ///
+    /// A merged card's record is moved to the new id, unless the map already
+    /// has a record for it, in which case the old one is discarded. A deleted
+    /// card's record is removed.
+    ///
+    /// Returns the record that was discarded, if any.
+    pub fn apply<T, S: BuildHasher>(&self, records: &mut HashMap<Uuid, T, S>) -> Option<T> {
+        let record = records.remove(&self.old_scryfall_id)?;
+        match (self.migration_strategy, self.new_scryfall_id) {
+            (MigrationStrategy::Merge, Some(new_id)) if !records.contains_key(&new_id) => {
+                records.insert(new_id, record);
+                None
+            },
+            _ => Some(record),
+        }
+    }
+}
+
+/// Applies every migration to a map of records keyed by card id, as with
+/// [`Migration::apply`].
+///
+/// The migrations are applied from the oldest to the newest, no matter the
+/// order they're given in, so a card that was merged more than once ends up
+/// under its latest id. Migrations performed on the same day are applied
+/// again until none of them moves a record, since their order within the day
+/// isn't known.
+///
+/// Returns the records that were discarded.
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::collections::HashMap;
+///
+/// use futures::stream::TryStreamExt;
+/// use scryfall::migration::{self, Migration};
+/// # tokio_test::block_on(async {
+/// let mut prices: HashMap<uuid::Uuid, f32> = HashMap::new();
+/// // ... fill in the prices of each card
+/// let migrations: Vec<Migration> = Migration::all()
+///     .await
+///     .unwrap()
+///     .into_stream()
+///     .try_collect()
+///     .await
+///     .unwrap();
+/// migration::apply_all(&migrations, &mut prices);
+/// # })
+/// ```
+pub fn apply_all<'a, T, S: BuildHasher>(
+    migrations: impl IntoIterator<Item = &'a Migration>,
+    records: &mut HashMap<Uuid, T, S>,
+) -> Vec<T> {
+    let mut migrations = migrations.into_iter().collect::<Vec<_>>();
+    migrations.sort_by_key(|m| m.performed_at);
+    let mut discarded = Vec::new();
+    for day in migrations.chunk_by(|a, b| a.performed_at == b.performed_at) {
+        // Each pass that moves a record removes one of the day's old ids, so
+        // this settles within as many passes as there are migrations.
+        for _ in 0..day.len() {
+            let mut moved = false;
+            for migration in day {
+                moved |= records.contains_key(&migration.old_scryfall_id);
+                discarded.extend(migration.apply(records));
+            }
+            if !moved {
+                break;
+            }
+        }
+    }
+    discarded
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream::TryStreamExt;
+    use serde_json::json;
+
+    use super::*;
+    use crate::client::{RateLimiter, ScryfallClient};
+    use crate::util::test_server::TestServer;
+
+    const OLD: &str = "2d0ac9ea-1ae2-4bd6-a2a7-b4a8ea1e11e1";
+    const MIDDLE: &str = "a7d4ddac-1d3f-4e8a-8d5a-5f0c2c9a2a45";
+    const NEW: &str = "c7b6b5b4-8f0a-4b36-9a3e-33c8d9a9b0e7";
+    const GONE: &str = "f1b1b1b1-0000-4000-8000-000000000001";
+
+    fn id(id: &str) -> Uuid {
+        id.parse().unwrap()
+    }
+
+    fn migration(date: &str, old: &str, new: Option<&str>) -> Migration {
+        serde_json::from_value(json!({
+            "object": "migration",
+            "id": "4f7ca4d9-5f22-4a47-9d11-d5bd2c2b8b1a",
+            "uri": "https://api.scryfall.com/migrations/4f7ca4d9-5f22-4a47-9d11-d5bd2c2b8b1a",
+            "performed_at": date,
+            "migration_strategy": if new.is_some() { "merge" } else { "delete" },
+            "old_scryfall_id": old,
+            "new_scryfall_id": new,
+            "note": null,
+            "metadata": null,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn applies_merges_and_deletions_in_order() {
+        let mut records = HashMap::from([(id(OLD), "bolt"), (id(GONE), "deleted")]);
+        // Given newest first, as Scryfall lists them.
+        let migrations = [
+            migration("2023-02-01", MIDDLE, Some(NEW)),
+            migration("2023-01-15", GONE, None),
+            migration("2023-01-01", OLD, Some(MIDDLE)),
+        ];
+        let discarded = apply_all(&migrations, &mut records);
+        assert_eq!(records, HashMap::from([(id(NEW), "bolt")]));
+        assert_eq!(discarded, ["deleted"]);
+    }
+
+    #[test]
+    fn follows_chains_merged_on_the_same_day() {
+        let mut records = HashMap::from([(id(OLD), "bolt")]);
+        let migrations = [
+            migration("2023-01-01", MIDDLE, Some(NEW)),
+            migration("2023-01-01", OLD, Some(MIDDLE)),
+        ];
+        apply_all(&migrations, &mut records);
+        assert_eq!(records, HashMap::from([(id(NEW), "bolt")]));
+
+        let mut records = HashMap::from([(id(OLD), "bolt")]);
+        apply_all(migrations.iter().rev(), &mut records);
+        assert_eq!(records, HashMap::from([(id(NEW), "bolt")]));
+    }
+
+    #[test]
+    fn merges_into_existing_records_keep_the_newer_one() {
+        let mut records = HashMap::from([(id(OLD), "old"), (id(NEW), "new")]);
+        let discarded = migration("2023-01-01", OLD, Some(NEW)).apply(&mut records);
+        assert_eq!(discarded, Some("old"));
+        assert_eq!(records, HashMap::from([(id(NEW), "new")]));
+
+        assert_eq!(
+            migration("2023-01-01", GONE, None).apply(&mut records),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_every_page_of_migrations() {
+        let page = |date: &str, old: &str, next: Option<&str>| {
+            let migration = serde_json::to_value(migration(date, old, None)).unwrap();
+            json!({
+                "object": "list",
+                "has_more": next.is_some(),
+                "next_page": next,
+                "data": [migration],
+            })
+            .to_string()
+        };
+        let server = TestServer::start(vec![
+            (
+                200,
+                page(
+                    "2023-02-01",
+                    NEW,
+                    Some("https://api.scryfall.com/migrations?page=2"),
+                ),
+            ),
+            (200, page("2023-01-01", OLD, None)),
+        ])
+        .await;
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .build()
+            .unwrap();
+
+        let migrations: Vec<Migration> = client
+            .scope(async {
+                Migration::all()
+                    .await
+                    .unwrap()
+                    .into_stream()
+                    .try_collect()
+                    .await
+            })
+            .await
+            .unwrap();
+        let old_ids = migrations
+            .iter()
+            .map(|m| m.old_scryfall_id)
+            .collect::<Vec<_>>();
+        assert_eq!(old_ids, [id(NEW), id(OLD)]);
+        assert_eq!(migrations[0].migration_strategy, MigrationStrategy::Delete);
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /migrations/ HTTP/1.1"));
+        assert!(requests[1].starts_with("GET /migrations?page=2 HTTP/1.1"));
+    }
+}
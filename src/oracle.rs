@@ -0,0 +1,450 @@
+//! A tokenizer for the rules text of cards.
+//!
+//! [`Tokenizer`] splits the Oracle or printed text of a card into its
+//! [`Ability`]s, and each ability into [`Span`]s of plain text, symbols,
+//! reminder text, references to the card itself and keywords. This is enough
+//! to render rules text with symbols and italic reminder text, or to search it
+//! without the reminder text getting in the way.
+//!
+//! The tokenizer doesn't understand the rules of the game: abilities are told
+//! apart by their wording, the way a player would at a glance.
+//!
+//! # Examples
+//! ```rust
+//! use scryfall::oracle::{AbilityKind, Span, Tokenizer};
+//!
+//! let tokenizer = Tokenizer::new().name("Llanowar Elves").keywords(["Flying"]);
+//! let abilities = tokenizer.tokenize("{T}: Add {G}. (Mana abilities can't be responded to.)");
+//! assert_eq!(abilities[0].kind, AbilityKind::Activated);
+//! assert_eq!(abilities[0].spans[0], Span::Symbol("{T}"));
+//! assert_eq!(abilities[0].plain_text(), "{T}: Add {G}.");
+//! ```
+use crate::card::Card;
+use crate::symbology::ManaSymbol;
+
+/// The words triggered abilities start with.
+const TRIGGER_WORDS: &[&str] = &["When", "Whenever", "At"];
+
+/// What kind of ability a line of rules text is.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum AbilityKind {
+    /// An ability with a cost, written as `cost: effect`, including loyalty
+    /// abilities.
+    Activated,
+    /// An ability that starts with "when", "whenever" or "at", possibly after
+    /// an ability word.
+    Triggered,
+    /// A list of keywords, such as `Flying, vigilance` or `Equip {2}`.
+    Keyword,
+    /// Anything else. This includes static abilities, and the effects of
+    /// instants and sorceries.
+    Static,
+}
+
+/// A piece of an [`Ability`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum Span<'a> {
+    /// Text with nothing special about it.
+    Text(&'a str),
+    /// A symbol in braces, such as `{T}` or `{2}{W}`'s `{W}`.
+    Symbol(&'a str),
+    /// Reminder text, with its parentheses. Reminder text may have symbols of
+    /// its own, which can be found by tokenizing it again.
+    Reminder(&'a str),
+    /// The name of the card, as the card refers to itself.
+    SelfReference(&'a str),
+    /// One of the card's keywords.
+    Keyword(&'a str),
+}
+
+impl<'a> Span<'a> {
+    /// The text of this span, exactly as it was in the rules text.
+    pub fn as_str(&self) -> &'a str {
+        match *self {
+            Span::Text(text)
+            | Span::Symbol(text)
+            | Span::Reminder(text)
+            | Span::SelfReference(text)
+            | Span::Keyword(text) => text,
+        }
+    }
+
+    /// The mana symbol of this span, if it's a symbol that can appear in a
+    /// mana cost.
+    pub fn mana_symbol(&self) -> Option<ManaSymbol> {
+        match self {
+            Span::Symbol(symbol) => symbol.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+/// An ability of a card, made of one line of its rules text, or several if it
+/// has modes written as bullet points.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub struct Ability<'a> {
+    /// What kind of ability this is.
+    pub kind: AbilityKind,
+    /// The text of the ability, exactly as it was in the rules text.
+    pub text: &'a str,
+    /// The pieces of the text, in order.
+    pub spans: Vec<Span<'a>>,
+}
+
+impl Ability<'_> {
+    /// The text of the ability without its reminder text.
+    pub fn plain_text(&self) -> String {
+        let text = self
+            .spans
+            .iter()
+            .filter(|span| !matches!(span, Span::Reminder(_)))
+            .map(Span::as_str)
+            .collect::<String>();
+        text.lines()
+            .map(|line| {
+                line.split(' ')
+                    .filter(|w| !w.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The mana symbols in this ability, outside of its reminder text.
+    pub fn mana_symbols(&self) -> impl Iterator<Item = ManaSymbol> + '_ {
+        self.spans.iter().filter_map(Span::mana_symbol)
+    }
+}
+
+/// Splits rules text into [`Ability`]s.
+///
+/// The tokenizer is told the names the card refers to itself by and its
+/// keywords, which are taken from a [`Card`] by [`Tokenizer::for_card`].
+#[derive(Clone, Debug, Default)]
+pub struct Tokenizer<'k> {
+    names: Vec<&'k str>,
+    keywords: Vec<&'k str>,
+}
+
+impl<'k> Tokenizer<'k> {
+    /// A tokenizer that knows no names or keywords.
+    pub fn new() -> Self {
+        Tokenizer::default()
+    }
+
+    /// A tokenizer with the names and keywords of `card`. The names of each
+    /// of its faces are included.
+    pub fn for_card(card: &'k Card) -> Self {
+        let faces = card.card_faces.iter().flatten().map(|face| &*face.name);
+        faces
+            .fold(Tokenizer::new().name(&card.name), Tokenizer::name)
+            .keywords(card.keywords.iter().map(|keyword| &**keyword))
+    }
+
+    /// Adds a name the card refers to itself by. Legendary cards often refer to
+    /// themselves by the part of their name before a comma, so that part is
+    /// added too. Empty names are ignored.
+    pub fn name(mut self, name: &'k str) -> Self {
+        let short = name.split_once(", ").map(|(short, _)| short);
+        self.names.extend(
+            [Some(name), short]
+                .into_iter()
+                .flatten()
+                .filter(|name| !name.is_empty()),
+        );
+        // Longer names are matched first, so a short name doesn't match the
+        // start of a full one.
+        self.names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+        self
+    }
+
+    /// Adds keywords to look for. They're matched ignoring case, as keywords
+    /// aren't capitalized in the middle of a sentence. Empty keywords are
+    /// ignored.
+    pub fn keywords(mut self, keywords: impl IntoIterator<Item = &'k str>) -> Self {
+        self.keywords
+            .extend(keywords.into_iter().filter(|keyword| !keyword.is_empty()));
+        self.keywords
+            .sort_by_key(|keyword| std::cmp::Reverse(keyword.len()));
+        self
+    }
+
+    /// Splits `text` into abilities. Each line is an ability, except for lines
+    /// starting with a bullet point, which are modes of the ability before
+    /// them.
+    pub fn tokenize<'t>(&self, text: &'t str) -> Vec<Ability<'t>> {
+        let mut abilities = Vec::new();
+        let mut push = |text: &'t str| {
+            let text = text.trim();
+            if !text.is_empty() {
+                abilities.push(self.ability(text));
+            }
+        };
+        let (mut start, mut offset) = (0, 0);
+        for line in text.split_inclusive('\n') {
+            if !line.trim_start().starts_with('•') {
+                push(&text[start..offset]);
+                start = offset;
+            }
+            offset += line.len();
+        }
+        push(&text[start..]);
+        abilities
+    }
+
+    fn ability<'t>(&self, text: &'t str) -> Ability<'t> {
+        let spans = self.spans(text);
+        Ability {
+            kind: self.kind(&spans),
+            text,
+            spans,
+        }
+    }
+
+    fn spans<'t>(&self, text: &'t str) -> Vec<Span<'t>> {
+        let mut spans = Vec::new();
+        let mut plain = 0;
+        let mut i = 0;
+        while i < text.len() {
+            let rest = &text[i..];
+            let special = if rest.starts_with('{') {
+                rest.find('}').map(|end| Span::Symbol(&rest[..=end]))
+            } else if rest.starts_with('(') {
+                Some(Span::Reminder(&rest[..closing_paren(rest)]))
+            } else if starts_word(text, i) {
+                self.word_span(rest)
+            } else {
+                None
+            };
+            match special {
+                Some(span) => {
+                    if plain < i {
+                        spans.push(Span::Text(&text[plain..i]));
+                    }
+                    i += span.as_str().len();
+                    plain = i;
+                    spans.push(span);
+                },
+                None => i += rest.chars().next().map_or(1, char::len_utf8),
+            }
+        }
+        if plain < text.len() {
+            spans.push(Span::Text(&text[plain..]));
+        }
+        spans
+    }
+
+    /// A self reference or keyword at the start of `rest`.
+    fn word_span<'t>(&self, rest: &'t str) -> Option<Span<'t>> {
+        let matches = |word: &str, ignore_case: bool| {
+            let candidate = rest.get(..word.len())?;
+            let equal = if ignore_case {
+                candidate.eq_ignore_ascii_case(word)
+            } else {
+                candidate == word
+            };
+            (equal && ends_word(rest, word.len())).then_some(candidate)
+        };
+        self.names
+            .iter()
+            .find_map(|name| matches(name, false))
+            .map(Span::SelfReference)
+            .or_else(|| {
+                self.keywords
+                    .iter()
+                    .find_map(|keyword| matches(keyword, true))
+                    .map(Span::Keyword)
+            })
+    }
+
+    fn kind(&self, spans: &[Span]) -> AbilityKind {
+        let text = spans
+            .iter()
+            .filter(|span| !matches!(span, Span::Reminder(_)))
+            .map(Span::as_str)
+            .collect::<String>();
+        let first_line = text.lines().next().unwrap_or("").trim();
+
+        if !first_line.is_empty()
+            && first_line
+                .split([',', ';'])
+                .all(|part| self.starts_with_keyword(part.trim()))
+        {
+            return AbilityKind::Keyword;
+        }
+
+        let mut in_quotes = false;
+        for c in first_line.chars() {
+            match c {
+                '"' | '“' | '”' => in_quotes = !in_quotes,
+                ':' if !in_quotes => return AbilityKind::Activated,
+                _ => {},
+            }
+        }
+
+        let body = first_line
+            .split_once("— ")
+            .map_or(first_line, |(_, body)| body);
+        let is_trigger = |line: &str| {
+            TRIGGER_WORDS
+                .iter()
+                .any(|word| line.starts_with(word) && ends_word(line, word.len()))
+        };
+        if is_trigger(first_line) || is_trigger(body) {
+            return AbilityKind::Triggered;
+        }
+        AbilityKind::Static
+    }
+
+    fn starts_with_keyword(&self, text: &str) -> bool {
+        self.keywords.iter().any(|keyword| {
+            text.get(..keyword.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(keyword))
+                && ends_word(text, keyword.len())
+        })
+    }
+}
+
+/// The length of the parenthesized text at the start of `text`, or of all of
+/// `text` if the parentheses aren't closed.
+fn closing_paren(text: &str) -> usize {
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            },
+            _ => {},
+        }
+    }
+    text.len()
+}
+
+/// Returns true if a word starts at byte `i` of `text`.
+fn starts_word(text: &str, i: usize) -> bool {
+    text[..i]
+        .chars()
+        .next_back()
+        .is_none_or(|c| !c.is_alphanumeric())
+}
+
+/// Returns true if a word ends at byte `i` of `text`.
+fn ends_word(text: &str, i: usize) -> bool {
+    text[i..]
+        .chars()
+        .next()
+        .is_none_or(|c| !c.is_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::card::Color;
+    use crate::util::test_card::card;
+
+    #[test]
+    fn splits_abilities_and_tells_their_kinds_apart() {
+        let text = "Flying, first strike (This creature deals combat damage first.)\n\
+                    Whenever Baneslayer deals damage, you gain that much life.\n\
+                    Landfall — At the beginning of your upkeep, scry 1.\n\
+                    {2}{W}, {T}: Baneslayer gets +1/+1 until end of turn.\n\
+                    Equipped creature has \"{T}: Add {W}.\"\n\
+                    Choose one —\n\
+                    • Draw a card.\n\
+                    • Gain 3 life.";
+        let tokenizer = Tokenizer::new()
+            .name("Baneslayer")
+            .keywords(["Flying", "First strike"]);
+        let abilities = tokenizer.tokenize(text);
+        assert_eq!(
+            abilities.iter().map(|a| a.kind).collect::<Vec<_>>(),
+            [
+                AbilityKind::Keyword,
+                AbilityKind::Triggered,
+                AbilityKind::Triggered,
+                AbilityKind::Activated,
+                AbilityKind::Static,
+                AbilityKind::Static,
+            ]
+        );
+        assert_eq!(
+            abilities[5].text,
+            "Choose one —\n• Draw a card.\n• Gain 3 life."
+        );
+        assert_eq!(abilities.last().unwrap().plain_text().lines().count(), 3);
+    }
+
+    #[test]
+    fn tokenizes_spans() {
+        let tokenizer = Tokenizer::new()
+            .name("Thalia, Guardian of Thraben")
+            .keywords(["First strike"]);
+        let abilities = tokenizer
+            .tokenize("First strike (Like a knight.)\nThalia's spells cost {1} more. Thaliaz");
+        assert_eq!(
+            abilities[0].spans,
+            [
+                Span::Keyword("First strike"),
+                Span::Text(" "),
+                Span::Reminder("(Like a knight.)"),
+            ]
+        );
+        assert_eq!(abilities[0].plain_text(), "First strike");
+        assert_eq!(
+            abilities[1].spans,
+            [
+                Span::SelfReference("Thalia"),
+                Span::Text("'s spells cost "),
+                Span::Symbol("{1}"),
+                Span::Text(" more. Thaliaz"),
+            ]
+        );
+        assert_eq!(
+            abilities[1].mana_symbols().collect::<Vec<_>>(),
+            [ManaSymbol::Generic(1)]
+        );
+    }
+
+    #[test]
+    fn ignores_empty_names_and_keywords() {
+        let tokenizer = Tokenizer::new()
+            .name("")
+            .name(", the Empty")
+            .keywords(["", "Haste"]);
+        let abilities = tokenizer.tokenize("Flying, haste");
+        assert_eq!(
+            abilities[0].spans,
+            [Span::Text("Flying, "), Span::Keyword("haste")]
+        );
+    }
+
+    #[test]
+    fn tokenizes_the_oracle_text_of_cards() {
+        let elves = card(json!({
+            "name": "Llanowar Elves",
+            "oracle_text": "{T}: Add {G}.",
+            "keywords": [],
+        }));
+        let abilities = elves.oracle_abilities();
+        assert_eq!(abilities.len(), 1);
+        assert_eq!(abilities[0].kind, AbilityKind::Activated);
+        assert_eq!(
+            abilities[0].mana_symbols().collect::<Vec<_>>(),
+            [ManaSymbol::Colored(Color::Green)]
+        );
+        assert_eq!(
+            Tokenizer::for_card(&elves).tokenize("Llanowar Elves attacks")[0].spans[0],
+            Span::SelfReference("Llanowar Elves")
+        );
+    }
+}
@@ -12,6 +12,7 @@ use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::card::CollectorNumber;
 use crate::list::ListIter;
 use crate::uri::Uri;
 use crate::util::{API_RULING, CARDS_URL};
@@ -28,7 +29,6 @@ use crate::util::{API_RULING, CARDS_URL};
 ///
 /// For more information, refer to the [official docs](https://scryfall.com/docs/api/rulings).
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Ruling {
     /// A unique ID for the oracle identity of the card this ruling is about.
@@ -47,6 +47,15 @@ pub struct Ruling {
     /// The text of the ruling.
     pub comment: String,
 
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<Ruling, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
+
     #[cfg(test)]
     #[serde(rename = "object")]
     _object: String,
@@ -54,7 +63,6 @@ pub struct Ruling {
 
 /// The two possible ruling sources
 #[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "snake_case")]
 #[allow(missing_docs)]
 pub enum Source {
@@ -250,7 +258,11 @@ impl Ruling {
     /// );
     /// # })
     /// ```
-    pub async fn set_and_number(set: &str, number: u32) -> crate::Result<ListIter<Self>> {
+    pub async fn set_and_number(
+        set: &str,
+        number: impl Into<CollectorNumber>,
+    ) -> crate::Result<ListIter<Self>> {
+        let number = number.into();
         Uri::from(
             CARDS_URL
                 .join(&format!("{set}/{number}/"))?
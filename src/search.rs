@@ -130,256 +130,293 @@ pub mod prelude {
 #[cfg(test)]
 mod tests {
     use super::prelude::*;
+    use crate::util::test_cassette::with_cassette;
     use crate::Card;
 
     use futures::stream::StreamExt;
 
     #[tokio::test]
+    #[ignore]
     async fn basic_search() {
-        let cards = SearchOptions::new()
-            .query(Query::And(vec![
-                name("lightning"),
-                name("helix"),
-                cmc(eq(2)),
-            ]))
-            .unique(UniqueStrategy::Prints)
-            .search()
-            .await
-            .unwrap()
-            .into_stream()
-            .map(|c| c.unwrap())
-            .collect::<Vec<_>>()
-            .await;
+        with_cassette("basic_search", async {
+            let cards = SearchOptions::new()
+                .query(Query::And(vec![
+                    name("lightning"),
+                    name("helix"),
+                    cmc(eq(2)),
+                ]))
+                .unique(UniqueStrategy::Prints)
+                .search()
+                .await
+                .unwrap()
+                .into_stream()
+                .map(|c| c.unwrap())
+                .collect::<Vec<_>>()
+                .await;
 
-        assert!(cards.len() > 1);
+            assert!(cards.len() > 1);
 
-        for card in cards {
-            assert_eq!(card.name, "Lightning Helix")
-        }
+            for card in cards {
+                assert_eq!(card.name, "Lightning Helix")
+            }
+        })
+        .await
     }
 
     #[tokio::test]
+    #[ignore]
     async fn basic_search_buffered() {
-        let cards = SearchOptions::new()
-            .query(Query::And(vec![
-                name("lightning"),
-                name("helix"),
-                cmc(eq(2)),
-            ]))
-            .unique(UniqueStrategy::Prints)
-            .search()
-            .await
-            .unwrap()
-            .into_stream_buffered(10)
-            .map(|c| c.unwrap())
-            .collect::<Vec<_>>()
-            .await;
+        with_cassette("basic_search_buffered", async {
+            let cards = SearchOptions::new()
+                .query(Query::And(vec![
+                    name("lightning"),
+                    name("helix"),
+                    cmc(eq(2)),
+                ]))
+                .unique(UniqueStrategy::Prints)
+                .search()
+                .await
+                .unwrap()
+                .into_stream_buffered(10)
+                .map(|c| c.unwrap())
+                .collect::<Vec<_>>()
+                .await;
 
-        assert!(cards.len() > 1);
+            assert!(cards.len() > 1);
 
-        for card in cards {
-            assert_eq!(card.name, "Lightning Helix")
-        }
+            for card in cards {
+                assert_eq!(card.name, "Lightning Helix")
+            }
+        })
+        .await
     }
 
     #[tokio::test]
+    #[ignore]
     async fn random_works_with_search_options() {
-        // `SearchOptions` can set more query params than the "cards/random" API method
-        // accepts. Scryfall should ignore these and return a random card.
-        assert!(SearchOptions::new()
-            .query(keyword("storm"))
-            .unique(UniqueStrategy::Art)
-            .sort(SortOrder::Usd, SortDirection::Ascending)
-            .extras(true)
-            .multilingual(true)
-            .variations(true)
-            .random()
-            .await
-            .unwrap()
-            .oracle_text
-            .unwrap()
-            .to_lowercase()
-            .contains("storm"));
+        with_cassette("random_works_with_search_options", async {
+            // `SearchOptions` can set more query params than the "cards/random" API method
+            // accepts. Scryfall should ignore these and return a random card.
+            assert!(SearchOptions::new()
+                .query(keyword("storm"))
+                .unique(UniqueStrategy::Art)
+                .sort(SortOrder::Usd, SortDirection::Ascending)
+                .extras(true)
+                .multilingual(true)
+                .variations(true)
+                .random()
+                .await
+                .unwrap()
+                .oracle_text
+                .unwrap()
+                .to_lowercase()
+                .contains("storm"));
+        })
+        .await
     }
 
     #[tokio::test]
+    #[ignore]
     async fn finds_alpha_lotus() {
-        let mut search = SearchOptions::new();
-
-        search
-            .query(exact("Black Lotus"))
-            .unique(UniqueStrategy::Prints)
-            .sort(SortOrder::Released, SortDirection::Ascending);
-
-        eprintln!("search query: {}", search.query_string().unwrap());
-        assert_eq!(
-            Card::search(&search)
-                .await
-                .expect("search failed")
-                .into_stream()
-                .next()
-                .await
-                .expect("empty stream")
-                .expect("deserialization failed")
-                .set
-                .to_string(),
-            "lea",
-        );
+        with_cassette("finds_alpha_lotus", async {
+            let mut search = SearchOptions::new();
+
+            search
+                .query(exact("Black Lotus"))
+                .unique(UniqueStrategy::Prints)
+                .sort(SortOrder::Released, SortDirection::Ascending);
+
+            eprintln!("search query: {}", search.query_string().unwrap());
+            assert_eq!(
+                Card::search(&search)
+                    .await
+                    .expect("search failed")
+                    .into_stream()
+                    .next()
+                    .await
+                    .expect("empty stream")
+                    .expect("deserialization failed")
+                    .set
+                    .to_string(),
+                "lea",
+            );
+        })
+        .await
     }
 
     #[tokio::test]
+    #[ignore]
     async fn finds_alpha_lotus_buffered() {
-        let mut search = SearchOptions::new();
-
-        search
-            .query(exact("Black Lotus"))
-            .unique(UniqueStrategy::Prints)
-            .sort(SortOrder::Released, SortDirection::Ascending);
-
-        eprintln!("search query: {}", search.query_string().unwrap());
-        assert_eq!(
-            Card::search(&search)
-                .await
-                .expect("search failed")
-                .into_stream_buffered(10)
-                .next()
-                .await
-                .expect("empty stream")
-                .expect("deserialization failed")
-                .set
-                .to_string(),
-            "lea",
-        );
+        with_cassette("finds_alpha_lotus_buffered", async {
+            let mut search = SearchOptions::new();
+
+            search
+                .query(exact("Black Lotus"))
+                .unique(UniqueStrategy::Prints)
+                .sort(SortOrder::Released, SortDirection::Ascending);
+
+            eprintln!("search query: {}", search.query_string().unwrap());
+            assert_eq!(
+                Card::search(&search)
+                    .await
+                    .expect("search failed")
+                    .into_stream_buffered(10)
+                    .next()
+                    .await
+                    .expect("empty stream")
+                    .expect("deserialization failed")
+                    .set
+                    .to_string(),
+                "lea",
+            );
+        })
+        .await
     }
 
     #[tokio::test]
+    #[ignore]
     async fn rarity_comparison() {
-        use crate::card::Rarity;
-        // The cards with "Bonus" rarity (power nine in vma).
-        let cards = SearchOptions::new()
-            .query(rarity(gt(Rarity::Mythic)))
-            .search()
-            .await
-            .unwrap()
-            .into_stream()
-            .collect::<Vec<_>>()
-            .await;
-
-        assert!(cards.len() >= 9, "Couldn't find the Power Nine from VMA.");
-
-        assert!(cards
-            .into_iter()
-            .map(|c| c.unwrap())
-            .all(|c| c.rarity > Rarity::Mythic));
-    }
-
-    #[tokio::test]
-    async fn rarity_comparison_buffered() {
-        use crate::card::Rarity;
-        // The cards with "Bonus" rarity (power nine in vma).
-        let cards = SearchOptions::new()
-            .query(rarity(gt(Rarity::Mythic)))
-            .search()
-            .await
-            .expect("search failed")
-            .into_stream_buffered(10)
-            .collect::<Vec<_>>()
-            .await;
+        with_cassette("rarity_comparison", async {
+            use crate::card::Rarity;
+            // The cards with "Bonus" rarity (power nine in vma).
+            let cards = SearchOptions::new()
+                .query(rarity(gt(Rarity::Mythic)))
+                .search()
+                .await
+                .unwrap()
+                .into_stream()
+                .collect::<Vec<_>>()
+                .await;
 
-        assert!(cards.len() >= 9, "Couldn't find the Power Nine from VMA.");
+            assert!(cards.len() >= 9, "Couldn't find the Power Nine from VMA.");
 
-        assert!(
-            cards
+            assert!(cards
                 .into_iter()
                 .map(|c| c.unwrap())
-                .all(|c| c.rarity > Rarity::Mythic),
-            "rarity should above mythic"
-        );
+                .all(|c| c.rarity > Rarity::Mythic));
+        })
+        .await
     }
 
     #[tokio::test]
-    async fn numeric_property_comparison() {
-        let card = Card::search_random(Query::And(vec![
-            power(eq(NumProperty::Toughness)),
-            pow_tou(eq(NumProperty::Cmc)),
-            not(CardIs::Funny),
-            not(CardIs::Transform),
-            not(CardIs::Flip),
-            not(CardIs::ModalDfc),
-        ]))
+    #[ignore]
+    async fn rarity_comparison_buffered() {
+        with_cassette("rarity_comparison_buffered", async {
+            use crate::card::Rarity;
+            // The cards with "Bonus" rarity (power nine in vma).
+            let cards = SearchOptions::new()
+                .query(rarity(gt(Rarity::Mythic)))
+                .search()
+                .await
+                .expect("search failed")
+                .into_stream_buffered(10)
+                .collect::<Vec<_>>()
+                .await;
+
+            assert!(cards.len() >= 9, "Couldn't find the Power Nine from VMA.");
+
+            assert!(
+                cards
+                    .into_iter()
+                    .map(|c| c.unwrap())
+                    .all(|c| c.rarity > Rarity::Mythic),
+                "rarity should above mythic"
+            );
+        })
         .await
-        .unwrap();
-
-        let power = card
-            .power
-            .and_then(|s| s.parse::<u32>().ok())
-            .unwrap_or_default();
-        let toughness = card
-            .toughness
-            .and_then(|s| s.parse::<u32>().ok())
-            .unwrap_or_default();
-
-        assert_eq!(power, toughness);
-        assert_eq!(power + toughness, card.cmc.unwrap_or_default() as u32);
-
-        let card = Card::search(pow_tou(gt(NumProperty::Year)))
-            .await
-            .unwrap()
-            .into_stream()
-            .map(|c| c.unwrap())
-            .any(|c| async move { &c.name == "Infinity Elemental" })
-            .await;
-
-        assert!(card);
     }
 
     #[tokio::test]
-    async fn numeric_property_comparison_buffered() {
-        let card = loop {
+    #[ignore]
+    async fn numeric_property_comparison() {
+        with_cassette("numeric_property_comparison", async {
             let card = Card::search_random(Query::And(vec![
                 power(eq(NumProperty::Toughness)),
                 pow_tou(eq(NumProperty::Cmc)),
                 not(CardIs::Funny),
+                not(CardIs::Transform),
+                not(CardIs::Flip),
+                not(CardIs::ModalDfc),
             ]))
             .await
             .unwrap();
 
-            // dfcs have don't have power and toughness on the top level card
-            if card.power.is_some() || card.toughness.is_some() {
-                break card;
-            }
-        };
+            let power = card
+                .power
+                .and_then(|s| s.parse::<u32>().ok())
+                .unwrap_or_default();
+            let toughness = card
+                .toughness
+                .and_then(|s| s.parse::<u32>().ok())
+                .unwrap_or_default();
 
-        let power = card
-            .power
-            .and_then(|s| s.parse::<u32>().ok())
-            .unwrap_or_default();
-        let toughness = card
-            .toughness
-            .and_then(|s| s.parse::<u32>().ok())
-            .unwrap_or_default();
+            assert_eq!(power, toughness);
+            assert_eq!(power + toughness, card.cmc.unwrap_or_default() as u32);
 
-        assert_eq!(
-            power, toughness,
-            "power was not equal to toughness for card {}",
-            card.name
-        );
-        assert_eq!(
-            power + toughness,
-            card.cmc.unwrap_or_default() as u32,
-            "power and toughness added was not equal to cmc for card {}",
-            card.name
-        );
+            let card = Card::search(pow_tou(gt(NumProperty::Year)))
+                .await
+                .unwrap()
+                .into_stream()
+                .map(|c| c.unwrap())
+                .any(|c| async move { &c.name == "Infinity Elemental" })
+                .await;
 
-        let card = Card::search(pow_tou(gt(NumProperty::Year)))
-            .await
-            .unwrap()
-            .into_stream_buffered(10)
-            .map(|c| c.unwrap())
-            .any(|c| async move { &c.name == "Infinity Elemental" })
-            .await;
+            assert!(card);
+        })
+        .await
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn numeric_property_comparison_buffered() {
+        with_cassette("numeric_property_comparison_buffered", async {
+            let card = loop {
+                let card = Card::search_random(Query::And(vec![
+                    power(eq(NumProperty::Toughness)),
+                    pow_tou(eq(NumProperty::Cmc)),
+                    not(CardIs::Funny),
+                ]))
+                .await
+                .unwrap();
+
+                // dfcs have don't have power and toughness on the top level card
+                if card.power.is_some() || card.toughness.is_some() {
+                    break card;
+                }
+            };
+
+            let power = card
+                .power
+                .and_then(|s| s.parse::<u32>().ok())
+                .unwrap_or_default();
+            let toughness = card
+                .toughness
+                .and_then(|s| s.parse::<u32>().ok())
+                .unwrap_or_default();
+
+            assert_eq!(
+                power, toughness,
+                "power was not equal to toughness for card {}",
+                card.name
+            );
+            assert_eq!(
+                power + toughness,
+                card.cmc.unwrap_or_default() as u32,
+                "power and toughness added was not equal to cmc for card {}",
+                card.name
+            );
+
+            let card = Card::search(pow_tou(gt(NumProperty::Year)))
+                .await
+                .unwrap()
+                .into_stream_buffered(10)
+                .map(|c| c.unwrap())
+                .any(|c| async move { &c.name == "Infinity Elemental" })
+                .await;
 
-        assert!(card);
+            assert!(card);
+        })
+        .await
     }
 
     #[test]
@@ -390,4 +427,47 @@ mod tests {
             "q=%28cmc%3A4+AND+name%3A%22Yargle%22%29"
         );
     }
+
+    /// Pages through made up search results, not ones recorded from Scryfall.
+    /// The ignored tests above check the real API.
+    #[tokio::test]
+    async fn pages_through_synthetic_search_results() {
+        use serde_json::json;
+
+        use crate::client::RateLimiter;
+        use crate::util::test_card::card_json;
+        use crate::util::test_server::TestServer;
+        use crate::ScryfallClient;
+
+        let first_page = json!({
+            "object": "list",
+            "has_more": true,
+            "next_page": "https://api.scryfall.com/cards/search?q=name%3Ahelix&page=2",
+            "data": [card_json(json!({ "name": "Lightning Helix" }))],
+        });
+        let second_page = json!({
+            "object": "list",
+            "has_more": false,
+            "data": [card_json(json!({ "name": "Lightning Helix", "set": "rav" }))],
+        });
+        let server = TestServer::start(vec![
+            (200, first_page.to_string()),
+            (200, second_page.to_string()),
+        ])
+        .await;
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .build()
+            .unwrap();
+
+        let cards = client
+            .scope(async { name("helix").search_all().await.unwrap() })
+            .await;
+        assert_eq!(cards.len(), 2);
+        assert!(cards.iter().all(|card| card.name == "Lightning Helix"));
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /cards/search/?q=name%3A%22helix%22 "));
+        assert!(requests[1].starts_with("GET /cards/search?q=name%3Ahelix&page=2 "));
+    }
 }
@@ -412,26 +412,33 @@ mod tests {
 
     use super::*;
     use crate::search::Search;
+    use crate::util::test_cassette::with_cassette;
 
     #[tokio::test]
     #[ignore]
     async fn all_card_is() {
-        for criterion in CardIs::iter() {
-            Query::from(criterion)
-                .random()
-                .await
-                .unwrap_or_else(|_| panic!("Failed to get a card for {}", criterion));
-        }
+        with_cassette("all_card_is", async {
+            for criterion in CardIs::iter() {
+                Query::from(criterion)
+                    .random()
+                    .await
+                    .unwrap_or_else(|_| panic!("Failed to get a card for {}", criterion));
+            }
+        })
+        .await
     }
 
     #[tokio::test]
     #[ignore]
     async fn all_printing_is() {
-        for criterion in PrintingIs::iter() {
-            Query::from(criterion)
-                .random()
-                .await
-                .unwrap_or_else(|_| panic!("Failed to get a printing for {}", criterion));
-        }
+        with_cassette("all_printing_is", async {
+            for criterion in PrintingIs::iter() {
+                Query::from(criterion)
+                    .random()
+                    .await
+                    .unwrap_or_else(|_| panic!("Failed to get a printing for {}", criterion));
+            }
+        })
+        .await
     }
 }
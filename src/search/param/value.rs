@@ -213,9 +213,13 @@ pub trait ParamValue: fmt::Debug + fmt::Display {
 /// [`power()`] and [`toughness()`], can be directly compared against one
 /// another. See [`NumericComparableValue`] for more information.
 ///
-/// This trait is implemented for all numeric primitive types.
+/// This trait is implemented for all numeric primitive types, and for
+/// [`CollectorNumber`](crate::card::CollectorNumber).
 pub trait NumericValue: ParamValue {}
 
+impl ParamValue for crate::card::CollectorNumber {}
+impl NumericValue for crate::card::CollectorNumber {}
+
 macro_rules! impl_numeric_values {
     ($($Ty:ty,)*) => {
         $(
@@ -660,11 +664,15 @@ impl GameValue for crate::card::Game {}
 /// `LanguageValue` is used as an argument to [`language()`] and
 /// [`in_language()`].
 ///
-/// This trait is implemented for all `TextValue` types.
+/// This trait is implemented for the [`Languages`][crate::card::Languages]
+/// enum, and for all [`TextValue`] types, such as `String` and `&str`.
 pub trait LanguageValue: ParamValue {}
 
 impl<T: TextValue> LanguageValue for T {}
 
+impl ParamValue for crate::card::Languages {}
+impl LanguageValue for crate::card::Languages {}
+
 mod functions {
     use super::*;
     use crate::search::query::Query;
@@ -26,7 +26,6 @@ use crate::util::SETS_URL;
 ///
 /// For more details visit the [official docs](https://scryfall.com/docs/api/sets).
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[non_exhaustive]
 pub struct Set {
     /// A unique ID for this set on Scryfall that will not change.
@@ -37,12 +36,24 @@ pub struct Set {
 
     /// The unique code for this set on MTGO, which may differ from the regular
     /// code.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub mtgo_code: Option<String>,
 
     /// The unique code for this set on Arena, which may differ from the regular code.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub arena_code: Option<String>,
 
     /// This set’s ID on TCGplayer’s API, also known as the groupId.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub tcgplayer_id: Option<u64>,
 
     /// The English name of the set.
@@ -53,22 +64,42 @@ pub struct Set {
 
     /// The date the set was released or the first card was printed in the set
     /// (in GMT-8 Pacific time).
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub released_at: Option<NaiveDate>,
 
     /// The block code for this set, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub block_code: Option<String>,
 
     /// The block or group name code for this set, if any.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub block: Option<String>,
 
     /// The set code for the parent set, if any. promo and token sets often have
     /// a parent set.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub parent_set_code: Option<String>,
 
     /// The number of cards in this set.
     pub card_count: usize,
 
     /// The denominator for the set’s printed collector numbers.
+    #[cfg_attr(
+        feature = "extra_fields",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub printed_size: Option<usize>,
 
     /// True if this set was only released in a video game.
@@ -96,6 +127,15 @@ pub struct Set {
     /// cards in this set.
     pub search_uri: Uri<List<Card>>,
 
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<Set, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
+
     #[cfg(test)]
     #[serde(rename = "object")]
     _object: String,
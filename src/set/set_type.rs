@@ -15,7 +15,6 @@ use serde::{Deserialize, Serialize};
     ),
     non_exhaustive
 )]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "snake_case")]
 pub enum SetType {
     /// A yearly Magic core set (Tenth Edition, etc)
@@ -73,7 +72,10 @@ pub enum SetType {
         doc(cfg(any(feature = "unknown_variants", feature = "unknown_variants_slim")))
     )]
     #[cfg(feature = "unknown_variants")]
-    #[serde(untagged)]
+    #[serde(
+        untagged,
+        deserialize_with = "crate::client::schema_drift::unknown_variant::<SetType, _>"
+    )]
     /// Unknown set type
     Unknown(Box<str>),
     #[cfg_attr(
@@ -0,0 +1,242 @@
+//! Card symbols are the symbols that appear in mana costs, oracle text and
+//! other places on Magic cards, such as `{T}`, `{W/U}` or `{2}`.
+//!
+//! This module defines [`CardSymbol`], which describes each of them,
+//! [`ManaCost`], a parsed mana cost made of [`ManaSymbol`]s, and
+//! [`ParsedManaCost`], a mana cost as normalized by Scryfall.
+//!
+//! Visit the official [docs](https://scryfall.com/docs/api/card-symbols) for more documentation.
+mod mana_cost;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+pub use self::mana_cost::{ManaCost, ManaSymbol, ParseManaCostError};
+use crate::card::Color;
+use crate::list::ListIter;
+use crate::uri::Uri;
+use crate::util::SYMBOLOGY_URL;
+
+/// A symbol that may appear in a card's mana cost or oracle text.
+///
+/// For more details visit the [official docs](https://scryfall.com/docs/api/card-symbols).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[non_exhaustive]
+pub struct CardSymbol {
+    /// The plaintext symbol, often surrounded with curly braces `{}`. Note
+    /// that not all symbols are ASCII text, for example `{∞}`.
+    pub symbol: String,
+
+    /// An alternate version of this symbol, if it is possible to write it
+    /// without curly braces.
+    pub loose_variant: Option<String>,
+
+    /// An English snippet that describes this symbol. Appropriate for use in
+    /// alt text or other accessible communication formats.
+    pub english: String,
+
+    /// True if it is possible to write this symbol “backwards”. For example,
+    /// the official symbol `{U/P}` is sometimes written as `{P/U}` or `{P\U}`
+    /// in informal settings.
+    pub transposable: bool,
+
+    /// True if this is a mana symbol.
+    pub represents_mana: bool,
+
+    /// A decimal number representing this symbol’s mana value, also known as
+    /// the converted mana cost. Note that mana symbols from funny sets can
+    /// have fractional mana values.
+    pub mana_value: Option<f32>,
+
+    /// True if this symbol appears in a mana cost on any Magic card. For
+    /// example `{20}` has this field set to false because `{20}` only appears
+    /// in Oracle text, not mana costs.
+    pub appears_in_mana_costs: bool,
+
+    /// True if this symbol is only used on funny cards or Un-cards.
+    pub funny: bool,
+
+    /// The colors of this symbol.
+    pub colors: Vec<Color>,
+
+    /// True if the symbol is a hybrid mana symbol.
+    #[serde(default)]
+    pub hybrid: bool,
+
+    /// True if the symbol is a Phyrexian mana symbol.
+    #[serde(default)]
+    pub phyrexian: bool,
+
+    /// An array of plaintext versions of this symbol that Gatherer uses on
+    /// old cards to describe original printed text. For example: `{W}` has
+    /// `["oW", "ooW"]` as alternates.
+    pub gatherer_alternates: Option<Vec<String>>,
+
+    /// A URI to an SVG image of this symbol on Scryfall’s CDNs.
+    pub svg_uri: Option<Url>,
+
+    /// The mana value of this symbol, replaced by
+    /// [`mana_value`](Self::mana_value).
+    #[serde(default)]
+    pub cmc: Option<f32>,
+
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<CardSymbol, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
+
+    #[cfg(test)]
+    #[serde(rename = "object")]
+    _object: String,
+}
+
+impl CardSymbol {
+    /// Returns a [`ListIter`] of all the card symbols in Scryfall’s database.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::symbology::CardSymbol;
+    /// # tokio_test::block_on(async {
+    /// let symbols = CardSymbol::all().await.unwrap().into_inner().collect::<Vec<_>>();
+    /// assert!(symbols.iter().any(|s| s.symbol == "{W/U}" && s.hybrid));
+    /// # })
+    /// ```
+    pub async fn all() -> crate::Result<ListIter<CardSymbol>> {
+        Uri::from(SYMBOLOGY_URL.clone()).fetch_iter().await
+    }
+}
+
+/// A mana cost, as normalized by Scryfall. Returned by
+/// [`ManaCost::parse_remote`].
+///
+/// For more details visit the [official docs](https://scryfall.com/docs/api/card-symbols/parse-mana).
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[non_exhaustive]
+pub struct ParsedManaCost {
+    /// The normalized cost, with correctly-ordered and wrapped mana symbols.
+    /// See [`parsed`](ParsedManaCost::parsed) to read its symbols.
+    pub cost: String,
+
+    /// The mana value of the cost.
+    pub cmc: f32,
+
+    /// The colors of the given cost.
+    pub colors: Vec<Color>,
+
+    /// True if the cost is colorless.
+    pub colorless: bool,
+
+    /// True if the cost is monocolored.
+    pub monocolored: bool,
+
+    /// True if the cost is multicolored.
+    pub multicolored: bool,
+
+    /// Fields Scryfall sent that this crate doesn't know about.
+    #[cfg(feature = "extra_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra_fields")))]
+    #[serde(
+        flatten,
+        deserialize_with = "crate::client::schema_drift::extra_fields::<ParsedManaCost, _>"
+    )]
+    pub extra: crate::extra_fields::ExtraFields,
+
+    #[cfg(test)]
+    #[serde(rename = "object")]
+    _object: String,
+}
+
+impl ParsedManaCost {
+    /// Parses the normalized [`cost`](ParsedManaCost::cost) into its symbols.
+    ///
+    /// This fails if Scryfall used a symbol this crate doesn't know about,
+    /// which is the case for symbols added after this version of the crate.
+    pub fn parsed(&self) -> Result<ManaCost, ParseManaCostError> {
+        self.cost.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{RateLimiter, ScryfallClient};
+    use crate::util::test_server::TestServer;
+
+    #[test]
+    fn deserializes_card_symbols() {
+        let symbol: CardSymbol = serde_json::from_str(
+            r#"{
+                "object": "card_symbol",
+                "symbol": "{W/P}",
+                "svg_uri": "https://svgs.scryfall.io/card-symbols/WP.svg",
+                "loose_variant": null,
+                "english": "one white mana or two life",
+                "transposable": false,
+                "represents_mana": true,
+                "appears_in_mana_costs": true,
+                "mana_value": 1.0,
+                "hybrid": false,
+                "phyrexian": true,
+                "cmc": 1.0,
+                "funny": false,
+                "colors": ["W"],
+                "gatherer_alternates": ["{W/P}"]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(symbol.symbol, "{W/P}");
+        assert!(symbol.phyrexian && !symbol.hybrid);
+        assert_eq!(symbol.colors, [Color::White]);
+        assert_eq!(symbol.mana_value, Some(1.0));
+    }
+
+    #[tokio::test]
+    async fn parses_mana_costs_remotely() {
+        let server = TestServer::start(vec![(
+            200,
+            r#"{"object":"mana_cost","cost":"{X}{U}{R}","colors":["U","R"],"cmc":2.0,"colorless":false,"monocolored":false,"multicolored":true}"#,
+        )])
+        .await;
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .build()
+            .unwrap();
+
+        let cost = client.scope(ManaCost::parse_remote("RUx")).await.unwrap();
+        assert_eq!(cost.cost, "{X}{U}{R}");
+        assert_eq!(cost.colors, [Color::Blue, Color::Red]);
+        assert_eq!(
+            cost.parsed().unwrap().symbols(),
+            [
+                ManaSymbol::X,
+                ManaSymbol::Colored(Color::Blue),
+                ManaSymbol::Colored(Color::Red)
+            ]
+        );
+        assert!(server.requests()[0].starts_with("GET /symbology/parse-mana?cost=RUx HTTP/1.1"));
+    }
+
+    #[tokio::test]
+    async fn keeps_remote_costs_with_unknown_symbols() {
+        let server = TestServer::start(vec![(
+            200,
+            r#"{"object":"mana_cost","cost":"{2}{Q}","colors":[],"cmc":3.0,"colorless":true,"monocolored":false,"multicolored":false}"#,
+        )])
+        .await;
+        let client = ScryfallClient::builder()
+            .transport(server.clone())
+            .rate_limiter(RateLimiter::disabled())
+            .build()
+            .unwrap();
+
+        let cost = client.scope(ManaCost::parse_remote("2Q")).await.unwrap();
+        assert_eq!(cost.cost, "{2}{Q}");
+        assert!(cost.colorless);
+        assert!(cost.parsed().is_err());
+    }
+}
@@ -0,0 +1,477 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use super::ParsedManaCost;
+use crate::card::{Color, Colors};
+use crate::uri::Uri;
+use crate::util::SYMBOLOGY_URL;
+
+/// A mana symbol that may appear in a mana cost.
+///
+/// The symbols from the official API docs are supported, including the ones
+/// only used on funny cards, such as half mana.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::Color;
+/// use scryfall::symbology::ManaSymbol;
+///
+/// let symbol: ManaSymbol = "{W/U}".parse().unwrap();
+/// assert_eq!(symbol, ManaSymbol::Hybrid(Color::White, Color::Blue));
+/// assert_eq!(symbol.mana_value(), 1.0);
+/// assert_eq!(symbol.to_string(), "{W/U}");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum ManaSymbol {
+    /// An amount of generic mana, like `{2}`.
+    Generic(u32),
+    /// One mana of a color, like `{W}`.
+    Colored(Color),
+    /// One colorless mana, `{C}`.
+    Colorless,
+    /// One mana from a snow source, `{S}`.
+    Snow,
+    /// A variable amount of generic mana, `{X}`.
+    X,
+    /// A second variable amount of generic mana, `{Y}`.
+    Y,
+    /// A third variable amount of generic mana, `{Z}`.
+    Z,
+    /// One mana of either of two colors, like `{W/U}`.
+    Hybrid(Color, Color),
+    /// One colorless mana or one mana of a color, like `{C/W}`.
+    ColorlessHybrid(Color),
+    /// Two generic mana or one mana of a color, like `{2/W}`.
+    TwoBrid(Color),
+    /// One mana of a color or 2 life, like `{W/P}`.
+    Phyrexian(Color),
+    /// One mana of either of two colors or 2 life, like `{W/U/P}`.
+    HybridPhyrexian(Color, Color),
+    /// Half a mana of a color, like `{HW}`.
+    Half(Color),
+    /// Half a generic mana, `{½}`.
+    HalfGeneric,
+}
+
+impl ManaSymbol {
+    /// The amount this symbol adds to the mana value of a cost.
+    pub fn mana_value(&self) -> f32 {
+        self.half_mana_value() as f32 / 2.0
+    }
+
+    /// The mana value of this symbol, counted in halves so that it's always
+    /// an integer.
+    fn half_mana_value(&self) -> u64 {
+        match self {
+            ManaSymbol::Generic(amount) => u64::from(*amount) * 2,
+            ManaSymbol::X | ManaSymbol::Y | ManaSymbol::Z => 0,
+            ManaSymbol::TwoBrid(_) => 4,
+            ManaSymbol::Half(_) | ManaSymbol::HalfGeneric => 1,
+            _ => 2,
+        }
+    }
+
+    /// The colors of this symbol.
+    pub fn colors(&self) -> Colors {
+        match *self {
+            ManaSymbol::Colored(color)
+            | ManaSymbol::ColorlessHybrid(color)
+            | ManaSymbol::TwoBrid(color)
+            | ManaSymbol::Phyrexian(color)
+            | ManaSymbol::Half(color) => Colors::monocolor(color),
+            ManaSymbol::Hybrid(a, b) | ManaSymbol::HybridPhyrexian(a, b) => {
+                Colors::monocolor(a).with(b)
+            },
+            _ => Colors::colorless(),
+        }
+    }
+
+    /// Returns true if this symbol can be paid with mana of different kinds.
+    pub fn is_hybrid(&self) -> bool {
+        matches!(
+            self,
+            ManaSymbol::Hybrid(..)
+                | ManaSymbol::ColorlessHybrid(_)
+                | ManaSymbol::TwoBrid(_)
+                | ManaSymbol::HybridPhyrexian(..)
+        )
+    }
+
+    /// Returns true if this symbol can be paid with life.
+    pub fn is_phyrexian(&self) -> bool {
+        matches!(
+            self,
+            ManaSymbol::Phyrexian(_) | ManaSymbol::HybridPhyrexian(..)
+        )
+    }
+
+    /// Returns true if paying for this symbol requires mana of `color`, or
+    /// allows it as one of the options. Colorless mana counts as a color here.
+    fn has_pip(&self, color: Color) -> bool {
+        match self {
+            ManaSymbol::Colorless | ManaSymbol::ColorlessHybrid(_) if color == Color::Colorless => {
+                true
+            },
+            _ => color != Color::Colorless && self.colors().is(color),
+        }
+    }
+}
+
+/// Parses the single letter of a color.
+fn color(letter: &str) -> Option<Color> {
+    Some(match letter {
+        "W" => Color::White,
+        "U" => Color::Blue,
+        "B" => Color::Black,
+        "R" => Color::Red,
+        "G" => Color::Green,
+        _ => return None,
+    })
+}
+
+impl FromStr for ManaSymbol {
+    type Err = ParseManaCostError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseManaCostError::new(s, "unknown mana symbol");
+        let inner = s
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(|| ParseManaCostError::new(s, "mana symbols must be wrapped in braces"))?
+            .to_ascii_uppercase();
+
+        if !inner.is_empty() && inner.bytes().all(|b| b.is_ascii_digit()) {
+            return inner.parse().map(ManaSymbol::Generic).map_err(|_| error());
+        }
+        let parts = inner.split('/').collect::<Vec<_>>();
+        Ok(match parts[..] {
+            ["C"] => ManaSymbol::Colorless,
+            ["S"] => ManaSymbol::Snow,
+            ["X"] => ManaSymbol::X,
+            ["Y"] => ManaSymbol::Y,
+            ["Z"] => ManaSymbol::Z,
+            ["½"] => ManaSymbol::HalfGeneric,
+            [letter] => match letter.strip_prefix('H') {
+                Some(letter) => ManaSymbol::Half(color(letter).ok_or_else(error)?),
+                None => ManaSymbol::Colored(color(letter).ok_or_else(error)?),
+            },
+            ["C", b] => ManaSymbol::ColorlessHybrid(color(b).ok_or_else(error)?),
+            ["2", b] => ManaSymbol::TwoBrid(color(b).ok_or_else(error)?),
+            [a, "P"] => ManaSymbol::Phyrexian(color(a).ok_or_else(error)?),
+            [a, b] => ManaSymbol::Hybrid(color(a).ok_or_else(error)?, color(b).ok_or_else(error)?),
+            [a, b, "P"] => ManaSymbol::HybridPhyrexian(
+                color(a).ok_or_else(error)?,
+                color(b).ok_or_else(error)?,
+            ),
+            _ => return Err(error()),
+        })
+    }
+}
+
+impl fmt::Display for ManaSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ManaSymbol::Generic(amount) => write!(f, "{{{amount}}}"),
+            ManaSymbol::Colored(color) => write!(f, "{{{color}}}"),
+            ManaSymbol::Colorless => f.write_str("{C}"),
+            ManaSymbol::Snow => f.write_str("{S}"),
+            ManaSymbol::X => f.write_str("{X}"),
+            ManaSymbol::Y => f.write_str("{Y}"),
+            ManaSymbol::Z => f.write_str("{Z}"),
+            ManaSymbol::Hybrid(a, b) => write!(f, "{{{a}/{b}}}"),
+            ManaSymbol::ColorlessHybrid(color) => write!(f, "{{C/{color}}}"),
+            ManaSymbol::TwoBrid(color) => write!(f, "{{2/{color}}}"),
+            ManaSymbol::Phyrexian(color) => write!(f, "{{{color}/P}}"),
+            ManaSymbol::HybridPhyrexian(a, b) => write!(f, "{{{a}/{b}/P}}"),
+            ManaSymbol::Half(color) => write!(f, "{{H{color}}}"),
+            ManaSymbol::HalfGeneric => f.write_str("{½}"),
+        }
+    }
+}
+
+/// A mana cost, as a sequence of [`ManaSymbol`]s.
+///
+/// Mana costs are parsed from the notation used by Scryfall and on cards,
+/// like `{2}{W/U}{B/P}`, and displayed back the same way. The mana costs of
+/// cards can be parsed with [`Card::parse_mana_cost`] and
+/// [`CardFace::parse_mana_cost`].
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::{Color, Colors};
+/// use scryfall::symbology::ManaCost;
+///
+/// let cost: ManaCost = "{2}{W/U}{B/P}{X}".parse().unwrap();
+/// assert_eq!(cost.mana_value(), 4.0);
+/// assert_eq!(cost.colors(), Colors::ESPER);
+/// assert_eq!(cost.pips(Color::Blue), 1);
+/// assert_eq!(cost.to_string(), "{2}{W/U}{B/P}{X}");
+/// ```
+///
+/// [`Card::parse_mana_cost`]: crate::Card::parse_mana_cost
+/// [`CardFace::parse_mana_cost`]: crate::card::CardFace::parse_mana_cost
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct ManaCost {
+    symbols: Vec<ManaSymbol>,
+}
+
+impl ManaCost {
+    /// Creates a mana cost made of `symbols`, in that order.
+    pub fn new(symbols: Vec<ManaSymbol>) -> Self {
+        ManaCost { symbols }
+    }
+
+    /// The symbols of this cost, in order.
+    pub fn symbols(&self) -> &[ManaSymbol] {
+        &self.symbols
+    }
+
+    /// Returns true if this cost has no symbols, like the cost of a land.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// The mana value of this cost, also known as its converted mana cost.
+    /// This is exact, even with half mana symbols. `{X}`, `{Y}` and `{Z}`
+    /// count as zero.
+    pub fn mana_value(&self) -> f32 {
+        let halves = self
+            .symbols
+            .iter()
+            .map(ManaSymbol::half_mana_value)
+            .sum::<u64>();
+        halves as f32 / 2.0
+    }
+
+    /// The colors of this cost.
+    pub fn colors(&self) -> Colors {
+        self.symbols
+            .iter()
+            .fold(Colors::colorless(), |colors, symbol| {
+                colors.union(symbol.colors())
+            })
+    }
+
+    /// The number of symbols in this cost that can be paid with mana of
+    /// `color`. Hybrid symbols count for each of their colors. Use
+    /// [`Color::Colorless`] to count `{C}` symbols.
+    pub fn pips(&self, color: Color) -> usize {
+        self.symbols
+            .iter()
+            .filter(|symbol| symbol.has_pip(color))
+            .count()
+    }
+
+    /// Asks Scryfall to parse a mana cost written in plaintext. Scryfall is
+    /// lenient about the input: symbols may be missing their braces or be out
+    /// of order, as long as they can be understood.
+    ///
+    /// Costs that can't be understood are reported as an
+    /// [`Error::ScryfallError`](crate::Error::ScryfallError).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Color;
+    /// use scryfall::symbology::ManaCost;
+    /// # tokio_test::block_on(async {
+    /// let cost = ManaCost::parse_remote("RUx").await.unwrap();
+    /// assert_eq!(cost.cost, "{X}{U}{R}");
+    /// assert_eq!(cost.cmc, 2.0);
+    /// assert_eq!(cost.colors, [Color::Blue, Color::Red]);
+    /// assert!(cost.multicolored);
+    /// # })
+    /// ```
+    pub async fn parse_remote(text: &str) -> crate::Result<ParsedManaCost> {
+        let mut url = SYMBOLOGY_URL.join("parse-mana")?;
+        url.query_pairs_mut().append_pair("cost", text);
+        Uri::from(url).fetch().await
+    }
+}
+
+impl FromStr for ManaCost {
+    type Err = ParseManaCostError;
+
+    /// Parses a mana cost such as `{2}{W/U}`. An empty string is an empty
+    /// cost.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut symbols = Vec::new();
+        let mut rest = s.trim();
+        while !rest.is_empty() {
+            let end = match (rest.starts_with('{'), rest.find('}')) {
+                (true, Some(end)) => end + 1,
+                _ => {
+                    return Err(ParseManaCostError::new(
+                        s,
+                        "mana symbols must be wrapped in braces",
+                    ))
+                },
+            };
+            symbols.push(
+                rest[..end]
+                    .parse()
+                    .map_err(|e: ParseManaCostError| ParseManaCostError::new(s, e.reason))?,
+            );
+            rest = &rest[end..];
+        }
+        Ok(ManaCost { symbols })
+    }
+}
+
+impl fmt::Display for ManaCost {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.symbols
+            .iter()
+            .try_for_each(|symbol| fmt::Display::fmt(symbol, f))
+    }
+}
+
+impl From<Vec<ManaSymbol>> for ManaCost {
+    fn from(symbols: Vec<ManaSymbol>) -> Self {
+        ManaCost::new(symbols)
+    }
+}
+
+impl Serialize for ManaCost {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ManaCost {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// The error returned when a mana cost or symbol can't be parsed.
+#[derive(Clone, Eq, PartialEq, Debug, thiserror::Error)]
+#[error("invalid mana cost {cost:?}: {reason}")]
+pub struct ParseManaCostError {
+    cost: String,
+    reason: &'static str,
+}
+
+impl ParseManaCostError {
+    fn new(cost: &str, reason: &'static str) -> Self {
+        ParseManaCostError {
+            cost: cost.to_string(),
+            reason,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::util::test_card::card;
+
+    fn cost(text: &str) -> ManaCost {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_every_kind_of_symbol() {
+        use ManaSymbol::*;
+
+        let text = "{10}{W}{C}{S}{X}{Y}{Z}{W/U}{C/G}{2/B}{R/P}{G/W/P}{HR}{½}";
+        assert_eq!(
+            cost(text).symbols(),
+            [
+                Generic(10),
+                Colored(Color::White),
+                Colorless,
+                Snow,
+                X,
+                Y,
+                Z,
+                Hybrid(Color::White, Color::Blue),
+                ColorlessHybrid(Color::Green),
+                TwoBrid(Color::Black),
+                Phyrexian(Color::Red),
+                HybridPhyrexian(Color::Green, Color::White),
+                Half(Color::Red),
+                HalfGeneric,
+            ]
+        );
+        assert_eq!(cost(text).to_string(), text);
+        assert_eq!(cost("{w/u}{2}").to_string(), "{W/U}{2}");
+    }
+
+    #[test]
+    fn calculates_mana_value_exactly() {
+        assert_eq!(cost("").mana_value(), 0.0);
+        assert_eq!(cost("{X}{X}{R}").mana_value(), 1.0);
+        assert_eq!(cost("{2/W}{2/W}{2/W}").mana_value(), 6.0);
+        assert_eq!(cost("{1}{HW}").mana_value(), 1.5);
+        assert_eq!(cost("{1000000}{½}").mana_value(), 1_000_000.5);
+    }
+
+    #[test]
+    fn counts_colors_and_pips() {
+        let jund = cost("{B}{R/G}{R}{C}");
+        assert_eq!(jund.colors(), Colors::JUND);
+        assert_eq!(jund.pips(Color::Red), 2);
+        assert_eq!(jund.pips(Color::Green), 1);
+        assert_eq!(jund.pips(Color::White), 0);
+        assert_eq!(jund.pips(Color::Colorless), 1);
+        assert!(cost("{3}{C}").colors().is_colorless());
+    }
+
+    #[test]
+    fn rejects_malformed_costs() {
+        for text in [
+            "{2}{W",
+            "2W",
+            "{}",
+            "{Q}",
+            "{W/Q}",
+            "{W}{U/B/G}",
+            "{99999999999}",
+        ] {
+            assert!(text.parse::<ManaCost>().is_err(), "{text} was parsed");
+        }
+        assert_eq!(
+            "{2}{Q}".parse::<ManaCost>().unwrap_err().to_string(),
+            r#"invalid mana cost "{2}{Q}": unknown mana symbol"#
+        );
+    }
+
+    #[test]
+    fn parses_the_costs_of_cards() {
+        let bolt = card(json!({}));
+        assert_eq!(bolt.parse_mana_cost().unwrap(), Some(cost("{R}")));
+
+        let fire_ice = card(json!({ "name": "Fire // Ice", "mana_cost": "{1}{R} // {1}{U}" }));
+        let cost = fire_ice.parse_mana_cost().unwrap().unwrap();
+        assert_eq!(cost.mana_value(), 4.0);
+        assert_eq!(cost.colors(), Colors::IZZET);
+
+        let land = card(json!({ "mana_cost": "" }));
+        assert_eq!(land.parse_mana_cost().unwrap(), None);
+        let face = card(json!({ "mana_cost": null }));
+        assert_eq!(face.parse_mana_cost().unwrap(), None);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let cost = cost("{1}{G/U/P}");
+        let json = serde_json::to_string(&cost).unwrap();
+        assert_eq!(json, r#""{1}{G/U/P}""#);
+        assert_eq!(serde_json::from_str::<ManaCost>(&json).unwrap(), cost);
+    }
+}
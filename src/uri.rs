@@ -7,11 +7,11 @@ use std::convert::TryFrom;
 use std::marker::PhantomData;
 
 use httpstatus::StatusCode;
-use reqwest::header::{self, HeaderValue};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+use crate::client::{Response, ScryfallClient};
 use crate::error::Error;
 use crate::list::{List, ListIter};
 
@@ -23,7 +23,6 @@ use crate::list::{List, ListIter};
 /// and `fetch_all` are available, giving access to objects from all pages
 /// of the collection.
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
-#[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(transparent)]
 pub struct Uri<T> {
     url: Url,
@@ -85,29 +84,6 @@ impl<T> AsRef<str> for Uri<T> {
     }
 }
 
-fn create_client() -> reqwest::Client {
-    reqwest::Client::builder()
-        .default_headers(
-            [
-                (header::ACCEPT, HeaderValue::from_static("*/*")),
-                (header::USER_AGENT, HeaderValue::from_static("scryfall-rs")),
-            ]
-            .into_iter()
-            .collect(),
-        )
-        .build()
-        .unwrap()
-}
-#[cfg(test)]
-use create_client as client;
-
-#[cfg(not(test))]
-fn client() -> &'static reqwest::Client {
-    use std::sync::OnceLock;
-    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
-    CLIENT.get_or_init(create_client)
-}
-
 impl<T: DeserializeOwned> Uri<T> {
     /// Fetches a resource from the Scryfall API and deserializes it into a type
     /// `T`.
@@ -126,34 +102,23 @@ impl<T: DeserializeOwned> Uri<T> {
     /// # })
     /// ```
     pub async fn fetch(&self) -> crate::Result<T> {
-        match self.fetch_raw().await {
-            Ok(response) => match response.status().as_u16() {
-                200..=299 => response.json().await.map_err(|e| Error::ReqwestError {
-                    error: e.into(),
-                    url: self.url.clone(),
-                }),
+        self.fetch_with(&ScryfallClient::current()).await
+    }
+
+    /// Fetches a resource through `client` and deserializes it into a type
+    /// `T`.
+    pub async fn fetch_with(&self, client: &ScryfallClient) -> crate::Result<T> {
+        match self.fetch_raw_with(client).await {
+            Ok(response) => match response.status() {
+                200..=299 => response.json(client.schema_drift()).await,
                 status => Err(Error::HttpError(StatusCode::from(status))),
             },
             Err(e) => Err(e),
         }
     }
 
-    pub(crate) async fn fetch_raw(&self) -> crate::Result<reqwest::Response> {
-        match client().get(self.url.clone()).send().await {
-            Ok(response) => match response.status().as_u16() {
-                400..=599 => Err(Error::ScryfallError(response.json().await.map_err(
-                    |e| Error::ReqwestError {
-                        error: e.into(),
-                        url: self.url.clone(),
-                    },
-                )?)),
-                _ => Ok(response),
-            },
-            Err(e) => Err(Error::ReqwestError {
-                error: e.into(),
-                url: self.url.clone(),
-            }),
-        }
+    pub(crate) async fn fetch_raw_with(&self, client: &ScryfallClient) -> crate::Result<Response> {
+        client.get(&self.url).await
     }
 }
 
@@ -212,7 +177,16 @@ impl<T: DeserializeOwned + Send + Sync + Unpin> Uri<List<T>> {
     /// # })
     /// ```
     pub async fn fetch_iter(&self) -> crate::Result<ListIter<T>> {
-        Ok(self.fetch().await?.into_list_iter())
+        self.fetch_iter_with(&ScryfallClient::current()).await
+    }
+
+    /// Like [`fetch_iter`](Self::fetch_iter), but fetches this and all the
+    /// following pages through `client`.
+    pub async fn fetch_iter_with(&self, client: &ScryfallClient) -> crate::Result<ListIter<T>> {
+        Ok(self
+            .fetch_with(client)
+            .await?
+            .into_list_iter_with(client.clone()))
     }
 
     /// Eagerly fetch items from all pages of a list. If any of the pages fail
@@ -233,12 +207,18 @@ impl<T: DeserializeOwned + Send + Sync + Unpin> Uri<List<T>> {
     /// # })
     /// ```
     pub async fn fetch_all(&self) -> crate::Result<Vec<T>> {
+        self.fetch_all_with(&ScryfallClient::current()).await
+    }
+
+    /// Like [`fetch_all`](Self::fetch_all), but fetches every page through
+    /// `client`.
+    pub async fn fetch_all_with(&self, client: &ScryfallClient) -> crate::Result<Vec<T>> {
         let mut items = vec![];
-        let mut next_page = Some(self.fetch().await?);
+        let mut next_page = Some(self.fetch_with(client).await?);
         while let Some(page) = next_page {
-            items.extend(page.data.into_iter());
+            items.extend(page.data);
             next_page = match page.next_page {
-                Some(uri) => Some(uri.fetch().await?),
+                Some(uri) => Some(uri.fetch_with(client).await?),
                 None => None,
             };
         }
@@ -4,6 +4,15 @@ use serde::{Deserialize, Deserializer};
 use url::Url;
 
 pub(crate) mod streaming_deserializer;
+#[cfg(test)]
+pub(crate) mod test_alloc;
+#[cfg(test)]
+pub(crate) mod test_card;
+#[cfg(test)]
+pub(crate) mod test_cassette;
+#[cfg(test)]
+pub(crate) mod test_server;
+pub(crate) mod trace;
 
 /// The [scryfall](https://scryfall.com/docs/api) endpoint.
 pub static ROOT_URL: Lazy<Url> = Lazy::new(|| Url::parse("https://api.scryfall.com/").unwrap());
@@ -15,6 +24,10 @@ pub static SETS_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("sets/").unwrap());
 pub static BULK_DATA_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("bulk-data/").unwrap());
 /// The [catalog](https://scryfall.com/docs/api/catalogs) endpoint.
 pub static CATALOG_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("catalog/").unwrap());
+/// The [migrations](https://scryfall.com/docs/api/migrations) endpoint.
+pub static MIGRATIONS_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("migrations/").unwrap());
+/// The [symbology](https://scryfall.com/docs/api/card-symbols) endpoint.
+pub static SYMBOLOGY_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("symbology/").unwrap());
 
 /// The [rulings](https://scryfall.com/docs/api/rulings) path segment, which goes on the end of a
 /// card URL.
@@ -28,3 +41,10 @@ pub fn deserialize_or_none<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
 ) -> Result<Option<T>, D::Error> {
     T::deserialize(deserializer).map(Some).or(Ok(None))
 }
+
+/// Function for use with `#[serde(skip_serializing_if)]`, to leave out fields
+/// Scryfall only sends when they're not empty.
+#[cfg(feature = "extra_fields")]
+pub(crate) fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
@@ -1,63 +1,179 @@
 use std::fmt;
+use std::io::Read;
 use std::marker::Send;
+use std::time::Instant;
 
 use futures::Stream;
 use serde::de::DeserializeOwned;
 use serde::{de::Visitor, Deserialize, Deserializer};
 use tokio::io::AsyncRead;
-use tokio::sync::mpsc::{channel, Sender};
+use tokio::sync::mpsc::channel;
 use tokio_stream::wrappers::ReceiverStream;
 use tokio_util::io::SyncIoBridge;
 
+use crate::client::schema_drift::{self, DriftRecorder};
+use crate::util::trace;
 use crate::Error;
 
-pub fn create<Value, R>(reader: R) -> impl Stream<Item = Result<Value, Error>>
+pub fn create<Value, R>(
+    reader: R,
+    drift: Option<DriftRecorder>,
+) -> impl Stream<Item = Result<Value, Error>>
 where
     Value: DeserializeOwned + Send + 'static,
     R: AsyncRead + Unpin + Send + 'static,
 {
-    struct ItemVisitor<V> {
-        sender: Sender<Result<V, Error>>,
-    }
+    let (sender, receiver) = channel::<Result<Value, Error>>(50);
+
+    let sync_reader = SyncIoBridge::new(reader);
+    tokio::task::spawn_blocking(move || {
+        for_each_item(sync_reader, drift.as_ref(), |item| {
+            sender.blocking_send(item).is_ok()
+        });
+    });
 
-    impl<'de, V: Deserialize<'de>> Visitor<'de> for ItemVisitor<V> {
-        type Value = ();
+    ReceiverStream::new(receiver)
+}
 
-        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-            formatter.write_str("seq of items")
-        }
+/// Like [`create`], but deserializes in a plain thread and returns a blocking
+/// iterator, so no tokio runtime is needed.
+#[cfg(feature = "blocking")]
+pub fn create_blocking<Value, R>(
+    reader: R,
+    drift: Option<DriftRecorder>,
+) -> std::sync::mpsc::IntoIter<Result<Value, Error>>
+where
+    Value: DeserializeOwned + Send + 'static,
+    R: Read + Send + 'static,
+{
+    let (sender, receiver) = std::sync::mpsc::sync_channel::<Result<Value, Error>>(50);
+
+    std::thread::spawn(move || {
+        for_each_item(reader, drift.as_ref(), |item| sender.send(item).is_ok());
+    });
+
+    receiver.into_iter()
+}
+
+/// Hands each item of a json array to `emit`, until it returns false. The
+/// items' unknown parts are added to `drift`, if given.
+struct ItemVisitor<'a, V, F> {
+    emit: &'a mut F,
+    drift: Option<&'a DriftRecorder>,
+    _marker: std::marker::PhantomData<V>,
+}
 
-        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-        where
-            A: serde::de::SeqAccess<'de>,
-        {
-            loop {
-                let result = seq.next_element();
-                match result {
-                    Ok(Some(v)) => {
-                        if self.sender.blocking_send(Ok(v)).is_err() {
-                            break;
-                        }
-                    },
-                    Ok(None) => break,
-                    Err(e) => return Err(e),
-                }
+impl<'de, V, F> Visitor<'de> for ItemVisitor<'_, V, F>
+where
+    V: Deserialize<'de>,
+    F: FnMut(Result<V, Error>) -> bool,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("seq of items")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        loop {
+            let result = seq.next_element_seed(schema_drift::Recorded::new(self.drift));
+            match result {
+                Ok(Some(v)) => {
+                    if !(self.emit)(Ok(v)) {
+                        break;
+                    }
+                },
+                Ok(None) => break,
+                Err(e) => return Err(e),
             }
-            Ok(())
         }
+        Ok(())
     }
+}
 
-    let (sender, receiver) = channel::<Result<Value, Error>>(50);
+/// Deserializes the items of the json array in `reader` one at a time, handing
+/// each to `emit` until it returns false.
+fn for_each_item<Value, R>(
+    reader: R,
+    drift: Option<&DriftRecorder>,
+    mut emit: impl FnMut(Result<Value, Error>) -> bool,
+) where
+    Value: DeserializeOwned,
+    R: Read,
+{
+    let start = Instant::now();
+    let mut items = 0;
+    let mut counted = |item: Result<Value, Error>| {
+        items += usize::from(item.is_ok());
+        emit(item)
+    };
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let result = deserializer.deserialize_seq(ItemVisitor {
+        emit: &mut counted,
+        drift,
+        _marker: std::marker::PhantomData,
+    });
+    trace::bulk_parsed(items, start.elapsed());
+    if let Err(e) = result {
+        // The result is ignored because failing to emit just means the
+        // receiver has disconnected.
+        let _ = emit(Err(Error::JsonError(e)));
+    }
+}
 
-    let sync_reader = SyncIoBridge::new(reader);
-    tokio::task::spawn_blocking(move || {
-        let mut deserializer = serde_json::Deserializer::from_reader(sync_reader);
-        if let Err(e) = deserializer.deserialize_seq(ItemVisitor::<Value> {
-            sender: sender.clone(),
-        }) {
-            let _ = sender.blocking_send(Err(Error::JsonError(e))); //let _ = because error from calling send just means receiver has disconnected
+/// Deserializes the whole json array in `reader`, adding the items' unknown
+/// parts to `drift`, if given.
+pub fn collect<Value, R>(reader: R, drift: Option<&DriftRecorder>) -> Result<Vec<Value>, Error>
+where
+    Value: DeserializeOwned,
+    R: Read,
+{
+    let start = Instant::now();
+    let mut items = Vec::new();
+    let mut push = |item: Result<Value, Error>| {
+        items.extend(item.ok());
+        true
+    };
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    deserializer.deserialize_seq(ItemVisitor {
+        emit: &mut push,
+        drift,
+        _marker: std::marker::PhantomData,
+    })?;
+    deserializer.end()?;
+    trace::bulk_parsed(items.len(), start.elapsed());
+    Ok(items)
+}
+
+/// Deserializes the items of the json array in `json` one at a time, handing
+/// each to `emit`. The items may borrow from `json`.
+pub fn for_each_borrowed<'de, Value>(
+    json: &'de [u8],
+    mut emit: impl FnMut(Value),
+) -> Result<(), Error>
+where
+    Value: Deserialize<'de>,
+{
+    let start = Instant::now();
+    let mut items = 0;
+    let mut counted = |item: Result<Value, Error>| {
+        if let Ok(item) = item {
+            items += 1;
+            emit(item);
         }
+        true
+    };
+    let mut deserializer = serde_json::Deserializer::from_slice(json);
+    let result = deserializer.deserialize_seq(ItemVisitor {
+        emit: &mut counted,
+        drift: None,
+        _marker: std::marker::PhantomData,
     });
-
-    ReceiverStream::new(receiver)
+    trace::bulk_parsed(items, start.elapsed());
+    result?;
+    deserializer.end()?;
+    Ok(())
 }
@@ -0,0 +1,103 @@
+//! A card object for tests that don't talk to the API.
+use serde_json::Value;
+
+use crate::client::schema_drift::{self, DriftRecorder};
+use crate::Card;
+
+/// A printing of Lightning Bolt from Magic 2010.
+const LIGHTNING_BOLT: &str = r#"{
+    "object": "card",
+    "id": "e3285e6b-3e79-4d7c-bf96-d920f973b80c",
+    "oracle_id": "4457ed35-7c10-48c8-9776-456485fdf070",
+    "multiverse_ids": [191089],
+    "mtgo_id": 33926,
+    "lang": "en",
+    "name": "Lightning Bolt",
+    "uri": "https://api.scryfall.com/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80c",
+    "scryfall_uri": "https://scryfall.com/card/m10/146/lightning-bolt",
+    "prints_search_uri": "https://api.scryfall.com/cards/search?order=released&q=oracleid%3A4457ed35-7c10-48c8-9776-456485fdf070&unique=prints",
+    "rulings_uri": "https://api.scryfall.com/cards/e3285e6b-3e79-4d7c-bf96-d920f973b80c/rulings",
+    "layout": "normal",
+    "highres_image": true,
+    "image_status": "highres_scan",
+    "mana_cost": "{R}",
+    "cmc": 1.0,
+    "type_line": "Instant",
+    "oracle_text": "Lightning Bolt deals 3 damage to any target.",
+    "colors": ["R"],
+    "color_identity": ["R"],
+    "keywords": [],
+    "legalities": {
+        "standard": "not_legal",
+        "modern": "legal",
+        "legacy": "legal",
+        "vintage": "legal",
+        "commander": "legal",
+        "pauper": "legal"
+    },
+    "games": ["paper", "mtgo"],
+    "reserved": false,
+    "foil": true,
+    "nonfoil": true,
+    "finishes": ["nonfoil", "foil"],
+    "oversized": false,
+    "promo": false,
+    "reprint": true,
+    "variation": false,
+    "set_id": "0a7e3a4f-3b5f-4a3f-9f6e-7e0ac1d8c4b2",
+    "set": "m10",
+    "set_name": "Magic 2010",
+    "set_type": "core",
+    "set_uri": "https://api.scryfall.com/sets/0a7e3a4f-3b5f-4a3f-9f6e-7e0ac1d8c4b2",
+    "set_search_uri": "https://api.scryfall.com/cards/search?order=set&q=e%3Am10&unique=prints",
+    "scryfall_set_uri": "https://scryfall.com/sets/m10",
+    "collector_number": "146",
+    "digital": false,
+    "rarity": "common",
+    "artist": "Christopher Moeller",
+    "border_color": "black",
+    "frame": "2003",
+    "full_art": false,
+    "textless": false,
+    "booster": true,
+    "story_spotlight": false,
+    "released_at": "2009-07-17",
+    "prices": {
+        "usd": "1.99",
+        "usd_foil": "24.99",
+        "usd_etched": null,
+        "eur": "1.50",
+        "eur_foil": "18.00",
+        "tix": "0.03"
+    },
+    "image_updated_at": "2023-01-01T00:00:00.000Z"
+}"#;
+
+/// The json of [`LIGHTNING_BOLT`], with the fields in `overrides` replaced.
+/// Fields overridden with `null` are removed.
+pub fn card_json(overrides: Value) -> Value {
+    let mut card: Value = serde_json::from_str(LIGHTNING_BOLT).unwrap();
+    if let (Value::Object(card), Value::Object(overrides)) = (&mut card, overrides) {
+        for (key, value) in overrides {
+            if value.is_null() {
+                card.remove(&key);
+            } else {
+                card.insert(key, value);
+            }
+        }
+    }
+    card
+}
+
+/// Like [`card_json`], but deserialized into a [`Card`]. Panics if the card
+/// has fields or enum values the crate doesn't know about.
+pub fn card(overrides: Value) -> Card {
+    let drift = DriftRecorder::new();
+    let card = schema_drift::deserialize(card_json(overrides), Some(&drift)).unwrap();
+    let report = drift.take();
+    assert!(
+        report.is_empty(),
+        "the test card has unknown parts:\n{report}"
+    );
+    card
+}
@@ -0,0 +1,39 @@
+//! Runs tests that need the Scryfall API against cassettes recorded in
+//! `tests/cassettes`, or against the API itself when there is no cassette.
+use std::future::Future;
+use std::path::Path;
+
+use crate::client::{Cassette, RateLimiter};
+use crate::ScryfallClient;
+
+/// Runs `test` with a client that replays the cassette called `name`, if it
+/// has been recorded, or that talks to the real API otherwise.
+///
+/// Setting the `SCRYFALL_CASSETTE` environment variable to `record` records
+/// the cassette against the real API. Cassettes are never recorded unless
+/// asked to, and they must be recorded rather than written by hand, since the
+/// tests using them check that Scryfall accepts the requests they make.
+pub async fn with_cassette<F: Future>(name: &str, test: F) -> F::Output {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/cassettes")
+        .join(format!("{name}.json"));
+    let record = std::env::var("SCRYFALL_CASSETTE").as_deref() == Ok("record");
+
+    let mut builder = ScryfallClient::builder();
+    if record {
+        #[cfg(feature = "reqwest")]
+        builder.transport(Cassette::record(
+            path,
+            crate::client::transport::ReqwestTransport::new(),
+        ));
+        #[cfg(not(feature = "reqwest"))]
+        panic!("recording cassettes needs the `reqwest` feature");
+    } else if path.exists() {
+        let cassette = Cassette::replay(&path)
+            .unwrap_or_else(|e| panic!("can't replay cassette {}: {e}", path.display()));
+        builder
+            .transport(cassette)
+            .rate_limiter(RateLimiter::disabled());
+    }
+    builder.build().unwrap().scope(test).await
+}
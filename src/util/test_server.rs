@@ -0,0 +1,92 @@
+//! An in-memory [`Transport`] for tests that replies to each request with the
+//! next canned response, and records the requests it received.
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use futures::stream::{self, StreamExt};
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::client::transport::{Request, Response, Transport, TransportError};
+
+#[derive(Clone, Debug)]
+pub struct TestServer {
+    requests: Arc<Mutex<Vec<String>>>,
+    responses: Arc<Mutex<VecDeque<CannedResponse>>>,
+}
+
+/// A canned response, as a status code, extra headers and a body.
+pub type CannedResponse<B = Bytes> = (u16, Vec<(&'static str, &'static str)>, B);
+
+impl TestServer {
+    /// Starts a server replying with `responses`, given as a status code and
+    /// a body.
+    pub async fn start<B: Into<Bytes>>(responses: Vec<(u16, B)>) -> Self {
+        Self::start_with_headers(
+            responses
+                .into_iter()
+                .map(|(status, body)| (status, Vec::new(), body))
+                .collect(),
+        )
+        .await
+    }
+
+    /// Starts a server replying with `responses`, given as a status code,
+    /// extra headers and a body.
+    pub async fn start_with_headers<B: Into<Bytes>>(responses: Vec<CannedResponse<B>>) -> Self {
+        TestServer {
+            requests: Arc::new(Mutex::new(Vec::new())),
+            responses: Arc::new(Mutex::new(
+                responses
+                    .into_iter()
+                    .map(|(status, headers, body)| (status, headers, body.into()))
+                    .collect(),
+            )),
+        }
+    }
+
+    /// The requests received so far, written out like they'd be sent over
+    /// HTTP/1.1: the request line, the headers, a blank line and the body.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for TestServer {
+    async fn send(&self, request: Request) -> Result<Response, TransportError> {
+        let url = &request.url;
+        let mut text = format!("{} {}", request.method, url.path());
+        if let Some(query) = url.query() {
+            text.push('?');
+            text.push_str(query);
+        }
+        text.push_str(" HTTP/1.1\r\n");
+        for (name, value) in &request.headers {
+            text.push_str(&format!("{name}: {}\r\n", value.to_str().unwrap()));
+        }
+        text.push_str("\r\n");
+        if let Some(body) = &request.body {
+            text.push_str(&String::from_utf8_lossy(body));
+        }
+        self.requests.lock().unwrap().push(text);
+
+        let (status, headers, body) = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("test server ran out of responses");
+        let headers = headers
+            .into_iter()
+            .map(|(name, value)| {
+                (
+                    HeaderName::from_static(name),
+                    HeaderValue::from_static(value),
+                )
+            })
+            .collect::<HeaderMap>();
+        let body = stream::iter([Ok(body)]).boxed();
+        Ok(Response::new(status, headers, body))
+    }
+}
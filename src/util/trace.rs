@@ -0,0 +1,163 @@
+//! Instrumentation of requests, pages and bulk data downloads with `tracing`.
+//!
+//! Without the `tracing` feature every function in this module does nothing,
+//! so call sites don't need to be feature gated.
+use std::fmt::Display;
+use std::future::Future;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use http::Method;
+use url::Url;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "tracing")] {
+        use std::time::Instant;
+
+        use futures::{StreamExt, TryStreamExt};
+        use tracing::{field, Instrument, Span};
+
+        /// The span of a single request to the API, including its retries.
+        #[derive(Clone, Debug)]
+        pub(crate) struct RequestTrace {
+            span: Span,
+            start: Instant,
+        }
+
+        impl RequestTrace {
+            pub(crate) fn new(method: &Method, url: &Url) -> Self {
+                RequestTrace {
+                    span: tracing::debug_span!(
+                        "scryfall_request",
+                        %method,
+                        %url,
+                        cache = field::Empty,
+                        status = field::Empty,
+                        retries = field::Empty,
+                        latency_ms = field::Empty,
+                        bytes = field::Empty,
+                    ),
+                    start: Instant::now(),
+                }
+            }
+
+            /// Runs `future` inside this request's span.
+            pub(crate) fn in_scope<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+                future.instrument(self.span.clone())
+            }
+
+            /// Records whether the response cache had an entry for this
+            /// request: `hit`, `miss` or `revalidated`.
+            pub(crate) fn cache(&self, outcome: &'static str) {
+                self.span.record("cache", outcome);
+            }
+
+            pub(crate) fn retrying(&self, attempt: u32, delay: Duration, reason: &dyn Display) {
+                tracing::warn!(parent: &self.span, attempt, ?delay, %reason, "retrying request");
+            }
+
+            /// Records the outcome of the request, once its headers have been
+            /// received or it failed for good.
+            pub(crate) fn finished(&self, status: Option<u16>, attempts: u32) {
+                let latency = self.start.elapsed();
+                self.span.record("status", status);
+                self.span.record("retries", attempts.saturating_sub(1));
+                self.span.record("latency_ms", latency.as_millis() as u64);
+                tracing::debug!(parent: &self.span, status, ?latency, "request finished");
+            }
+
+            pub(crate) fn body_read(&self, bytes: usize) {
+                self.span.record("bytes", bytes);
+                tracing::debug!(parent: &self.span, bytes, "response body read");
+            }
+
+            /// Wraps `body` to record how many bytes were read from it once
+            /// it's dropped.
+            pub(crate) fn count_body(
+                &self,
+                body: BoxStream<'static, io::Result<Bytes>>,
+            ) -> BoxStream<'static, io::Result<Bytes>> {
+                struct Counter {
+                    trace: RequestTrace,
+                    bytes: usize,
+                }
+
+                impl Counter {
+                    fn add(&mut self, bytes: usize) {
+                        self.bytes += bytes;
+                    }
+                }
+
+                impl Drop for Counter {
+                    fn drop(&mut self) {
+                        self.trace.body_read(self.bytes);
+                    }
+                }
+
+                let mut counter = Counter {
+                    trace: self.clone(),
+                    bytes: 0,
+                };
+                body.inspect_ok(move |chunk| counter.add(chunk.len()))
+                    .boxed()
+            }
+        }
+
+        pub(crate) fn page(page: usize, remaining: Option<usize>) {
+            tracing::debug!(page, remaining, "fetching next page");
+        }
+
+        #[cfg_attr(not(feature = "bulk_caching"), allow(dead_code))]
+        pub(crate) fn bulk_cache(path: &Path, hit: bool) {
+            tracing::debug!(path = %path.display(), hit, "bulk data cache lookup");
+        }
+
+        pub(crate) fn bulk_downloaded(url: &Url, bytes: u64, elapsed: Duration) {
+            tracing::info!(%url, bytes, ?elapsed, "bulk data downloaded");
+        }
+
+        pub(crate) fn bulk_parsed(items: usize, elapsed: Duration) {
+            tracing::info!(items, ?elapsed, "bulk data parsed");
+        }
+    } else {
+        #[derive(Clone, Debug)]
+        pub(crate) struct RequestTrace;
+
+        impl RequestTrace {
+            pub(crate) fn new(_: &Method, _: &Url) -> Self {
+                RequestTrace
+            }
+
+            pub(crate) fn in_scope<F: Future>(&self, future: F) -> F {
+                future
+            }
+
+            pub(crate) fn cache(&self, _: &'static str) {}
+
+            pub(crate) fn retrying(&self, _: u32, _: Duration, _: &dyn Display) {}
+
+            pub(crate) fn finished(&self, _: Option<u16>, _: u32) {}
+
+            pub(crate) fn body_read(&self, _: usize) {}
+
+            pub(crate) fn count_body(
+                &self,
+                body: BoxStream<'static, io::Result<Bytes>>,
+            ) -> BoxStream<'static, io::Result<Bytes>> {
+                body
+            }
+        }
+
+        pub(crate) fn page(_: usize, _: Option<usize>) {}
+
+        #[cfg_attr(not(feature = "bulk_caching"), allow(dead_code))]
+        pub(crate) fn bulk_cache(_: &Path, _: bool) {}
+
+        pub(crate) fn bulk_downloaded(_: &Url, _: u64, _: Duration) {}
+
+        pub(crate) fn bulk_parsed(_: usize, _: Duration) {}
+    }
+}
@@ -1,15 +1,19 @@
 use scryfall::{
-    card::{Finishes, FrameEffect, Layout, PromoType, SecurityStamp},
+    card::{CardType, Finishes, FrameEffect, Languages, Layout, PromoType, SecurityStamp},
+    format::Format,
     set::SetType,
 };
 
 use static_assertions as sa;
 
+sa::assert_eq_size!(CardType, [u8; 24]);
+sa::assert_eq_size!(Format, [u8; 24]);
 sa::assert_eq_size!(FrameEffect, [u8; 24]);
 sa::assert_eq_size!(Layout, [u8; 24]);
 sa::assert_eq_size!(SetType, [u8; 24]);
 sa::assert_eq_size!(PromoType, [u8; 24]);
 sa::assert_eq_size!(SecurityStamp, [u8; 24]);
+sa::assert_eq_size!(Languages, [u8; 24]);
 
 #[allow(dead_code)]
 fn match_on_frame_effect(f: FrameEffect) {
@@ -244,6 +248,31 @@ fn match_on_security_stamp(s: SecurityStamp) {
     }
 }
 
+#[allow(dead_code)]
+fn match_on_card_type(t: CardType) {
+    match t {
+        CardType::Artifact => todo!(),
+        CardType::Battle => todo!(),
+        CardType::Conspiracy => todo!(),
+        CardType::Creature => todo!(),
+        CardType::Dungeon => todo!(),
+        CardType::Emblem => todo!(),
+        CardType::Enchantment => todo!(),
+        CardType::Hero => todo!(),
+        CardType::Instant => todo!(),
+        CardType::Kindred => todo!(),
+        CardType::Land => todo!(),
+        CardType::Phenomenon => todo!(),
+        CardType::Plane => todo!(),
+        CardType::Planeswalker => todo!(),
+        CardType::Scheme => todo!(),
+        CardType::Sorcery => todo!(),
+        CardType::Tribal => todo!(),
+        CardType::Vanguard => todo!(),
+        CardType::Unknown(_) => todo!(),
+    }
+}
+
 #[allow(dead_code)]
 fn match_on_finishes(f: Finishes) {
     match f {
@@ -256,6 +285,14 @@ fn match_on_finishes(f: Finishes) {
 
 #[test]
 fn deserialize() {
+    assert_eq!(
+        "Foo".parse::<CardType>().unwrap(),
+        CardType::Unknown("Foo".into())
+    );
+    assert_eq!(
+        serde_json::from_str::<Format>(r#""foo""#).unwrap(),
+        Format::Unknown("foo".into())
+    );
     assert_eq!(
         serde_json::from_str::<FrameEffect>(r#""foo""#).unwrap(),
         FrameEffect::Unknown("foo".into())
@@ -280,4 +317,8 @@ fn deserialize() {
         serde_json::from_str::<Finishes>(r#""foo""#).unwrap(),
         Finishes::Unknown("foo".into())
     );
+    assert_eq!(
+        serde_json::from_str::<Languages>(r#""foo""#).unwrap(),
+        Languages::Unknown("foo".into())
+    );
 }
@@ -1,21 +1,28 @@
 use scryfall::{
-    card::{Finishes, FrameEffect, Layout, PromoType, SecurityStamp},
+    card::{CardType, Finishes, FrameEffect, Languages, Layout, PromoType, SecurityStamp},
+    format::Format,
     set::SetType,
 };
 
 use static_assertions as sa;
 
+sa::assert_impl_all!(CardType: Copy);
+sa::assert_impl_all!(Format: Copy);
 sa::assert_impl_all!(FrameEffect: Copy);
 sa::assert_impl_all!(Layout: Copy);
 sa::assert_impl_all!(SetType: Copy);
 sa::assert_impl_all!(PromoType: Copy);
 sa::assert_impl_all!(SecurityStamp: Copy);
+sa::assert_impl_all!(Languages: Copy);
 
+sa::assert_eq_size!(CardType, u8);
+sa::assert_eq_size!(Format, u8);
 sa::assert_eq_size!(FrameEffect, u8);
 sa::assert_eq_size!(Layout, u8);
 sa::assert_eq_size!(SetType, u8);
 sa::assert_eq_size!(PromoType, u8);
 sa::assert_eq_size!(SecurityStamp, u8);
+sa::assert_eq_size!(Languages, u8);
 
 #[allow(dead_code)]
 fn match_on_frame_effect(f: FrameEffect) {
@@ -250,6 +257,31 @@ fn match_on_security_stamp(s: SecurityStamp) {
     }
 }
 
+#[allow(dead_code)]
+fn match_on_card_type(t: CardType) {
+    match t {
+        CardType::Artifact => todo!(),
+        CardType::Battle => todo!(),
+        CardType::Conspiracy => todo!(),
+        CardType::Creature => todo!(),
+        CardType::Dungeon => todo!(),
+        CardType::Emblem => todo!(),
+        CardType::Enchantment => todo!(),
+        CardType::Hero => todo!(),
+        CardType::Instant => todo!(),
+        CardType::Kindred => todo!(),
+        CardType::Land => todo!(),
+        CardType::Phenomenon => todo!(),
+        CardType::Plane => todo!(),
+        CardType::Planeswalker => todo!(),
+        CardType::Scheme => todo!(),
+        CardType::Sorcery => todo!(),
+        CardType::Tribal => todo!(),
+        CardType::Vanguard => todo!(),
+        CardType::Unknown => todo!(),
+    }
+}
+
 #[allow(dead_code)]
 fn match_on_finishes(f: Finishes) {
     match f {
@@ -262,6 +294,11 @@ fn match_on_finishes(f: Finishes) {
 
 #[test]
 fn deserialize() {
+    assert_eq!("Foo".parse::<CardType>().unwrap(), CardType::Unknown);
+    assert_eq!(
+        serde_json::from_str::<Format>(r#""frontier""#).unwrap(),
+        Format::Unknown,
+    );
     assert_eq!(
         serde_json::from_str::<FrameEffect>(r#""frontier""#).unwrap(),
         FrameEffect::Unknown,
@@ -286,4 +323,8 @@ fn deserialize() {
         serde_json::from_str::<Finishes>(r#""foo""#).unwrap(),
         Finishes::Unknown,
     );
+    assert_eq!(
+        serde_json::from_str::<Languages>(r#""foo""#).unwrap(),
+        Languages::Unknown,
+    );
 }